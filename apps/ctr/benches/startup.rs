@@ -0,0 +1,35 @@
+//! Startup cost of the ctr cli, with hundreds of messages across 13 services.
+//! Run with `cargo bench -p ctr --bench startup`.
+
+use std::time::Instant;
+
+use clap::{CommandFactory, Parser};
+use ctr::Args;
+
+const ITERATIONS: u32 = 50;
+
+/// Prints the time of the first run, which fills the caches, and the average of later runs.
+fn bench(name: &str, mut f: impl FnMut()) {
+    let start = Instant::now();
+    f();
+    let first = start.elapsed();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let avg = start.elapsed() / ITERATIONS;
+    println!("{name:<24} first {first:>12.3?}   avg {avg:>12.3?}");
+}
+
+fn parse(args: &[&str]) {
+    let prefix = ["ctr", "tcp", "--url", "http://127.0.0.1:1"];
+    Args::try_parse_from(prefix.iter().chain(args)).unwrap();
+}
+
+fn main() {
+    bench("version version", || parse(&["version", "version"]));
+    bench("tasks exec", || {
+        parse(&["tasks", "exec", "--container-id", "c1", "--exec-id", "e1"])
+    });
+    bench("full command tree", || Args::command().build());
+}
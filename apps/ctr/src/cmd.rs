@@ -36,21 +36,22 @@ impl tonic_clap::arg::ExecuteCmd for CombinedArgs {
         self,
         channel: tonic::transport::Channel,
         json_data: Option<String>,
-    ) -> Result<Box<dyn std::fmt::Debug>, tonic::Status> {
+        verbose: bool,
+    ) -> Result<tonic_clap::output::Responses, tonic_clap::Error> {
         match self {
-            CombinedArgs::Services(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Content(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Diff(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Events(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Images(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Introspection(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Leases(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Namespaces(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Sandbox(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Snapshots(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Tasks(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Transfer(cmd) => cmd.execute(channel, json_data).await,
-            CombinedArgs::Version(cmd) => cmd.execute(channel, json_data).await,
+            CombinedArgs::Services(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Content(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Diff(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Events(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Images(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Introspection(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Leases(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Namespaces(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Sandbox(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Snapshots(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Tasks(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Transfer(cmd) => cmd.execute(channel, json_data, verbose).await,
+            CombinedArgs::Version(cmd) => cmd.execute(channel, json_data, verbose).await,
         }
     }
 }
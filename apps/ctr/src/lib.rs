@@ -0,0 +1,11 @@
+pub mod cmd;
+pub mod included;
+
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+#[command(name = "ctr", version, about = "Containerd CLI tool", long_about = None)]
+pub struct Args {
+    #[command(flatten)]
+    pub default_args: tonic_clap::arg::DefaultArgs<cmd::CombinedArgs>,
+}
@@ -1,18 +1,8 @@
-pub mod cmd;
-pub mod included;
-
 use clap::Parser;
-
-#[derive(Parser, Debug)]
-#[command(name = "ctr", version, about = "Containerd CLI tool", long_about = None)]
-pub struct Args {
-    #[command(flatten)]
-    pub default_args: tonic_clap::arg::DefaultArgs<cmd::CombinedArgs>,
-}
+use ctr::Args;
 
 #[tokio::main(flavor = "current_thread")]
-async fn main() {
+async fn main() -> std::process::ExitCode {
     let args = Args::parse();
-    println!("Args: {:?}", args);
-    args.default_args.run_main().await.unwrap();
+    args.default_args.run().await
 }
@@ -1,4 +1,5 @@
-use tonic_clap_tests::helloworld::{self, EnumOk, HelloRequest};
+use tonic_clap::{call::unary, output::Responses};
+use tonic_clap_tests::helloworld::{self, EnumOk, HelloReply, HelloReply2, HelloRequest};
 
 #[derive(clap::Subcommand, Debug)]
 pub enum CommandServices {
@@ -26,20 +27,26 @@ impl tonic_clap::arg::ExecuteCmd for GreeterCommands {
         self,
         ch: tonic::transport::Channel,
         json_data: Option<String>,
-    ) -> Result<Box<dyn std::fmt::Debug>, tonic::Status> {
-        let mut c = helloworld::greeter_client::GreeterClient::new(ch);
+        verbose: bool,
+    ) -> Result<Responses, tonic_clap::Error> {
         match self {
             GreeterCommands::SayHello(args) => {
                 let mut request: HelloRequest = match json_data {
-                    Some(data) => serde_json::from_str(&data).unwrap(),
+                    Some(data) => tonic_clap::json::from_json_str(&data)?,
                     None => Default::default(),
                 };
                 args.apply(&mut request);
-                Ok(Box::new(c.say_hello(request).await?.into_inner()))
+                let path = "/helloworld.Greeter/SayHello";
+                Ok(unary::<_, HelloReply>(ch, path, request, verbose)
+                    .await?
+                    .into())
             }
             GreeterCommands::SayHello2(args) => {
-                let request = tonic::Request::new(args.clone().into());
-                Ok(Box::new(c.say_hello2(request).await?.into_inner()))
+                let request: helloworld::HelloRequest2 = args.into();
+                let path = "/helloworld.Greeter/SayHello2";
+                Ok(unary::<_, HelloReply2>(ch, path, request, verbose)
+                    .await?
+                    .into())
             }
         }
     }
@@ -49,17 +56,23 @@ impl Greeter2Commands {
     async fn execute(
         &self,
         ch: tonic::transport::Channel,
-    ) -> Result<Box<dyn std::fmt::Debug>, tonic::Status> {
-        let mut c = helloworld::greeter2_client::Greeter2Client::new(ch);
+        verbose: bool,
+    ) -> Result<Responses, tonic_clap::Error> {
         match self {
             Greeter2Commands::SayHello(args) => {
                 let mut request = HelloRequest::default();
                 args.apply(&mut request);
-                Ok(Box::new(c.say_hello(request).await?.into_inner()))
+                let path = "/helloworld.Greeter2/SayHello";
+                Ok(unary::<_, HelloReply>(ch, path, request, verbose)
+                    .await?
+                    .into())
             }
             Greeter2Commands::SayHello2(args) => {
-                let request = tonic::Request::new((*args).clone().into());
-                Ok(Box::new(c.say_hello2(request).await?.into_inner()))
+                let request: helloworld::HelloRequest2 = (*args).clone().into();
+                let path = "/helloworld.Greeter2/SayHello2";
+                Ok(unary::<_, HelloReply2>(ch, path, request, verbose)
+                    .await?
+                    .into())
             }
         }
     }
@@ -70,10 +83,11 @@ impl tonic_clap::arg::ExecuteCmd for CommandServices {
         self,
         ch: tonic::transport::Channel,
         json_data: Option<String>,
-    ) -> Result<Box<dyn std::fmt::Debug>, tonic::Status> {
+        verbose: bool,
+    ) -> Result<Responses, tonic_clap::Error> {
         match self {
-            CommandServices::Greeter(cmd) => cmd.execute(ch, json_data).await,
-            CommandServices::Greeter2(cmd) => cmd.execute(ch).await,
+            CommandServices::Greeter(cmd) => cmd.execute(ch, json_data, verbose).await,
+            CommandServices::Greeter2(cmd) => cmd.execute(ch, verbose).await,
         }
     }
 }
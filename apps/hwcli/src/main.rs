@@ -6,7 +6,7 @@ mod cligen;
 pub type Args = tonic_clap::arg::DefaultArgs<cligen::CommandServices>;
 
 #[tokio::main(flavor = "current_thread")]
-async fn main() -> Result<(), tonic_clap::Error> {
+async fn main() -> std::process::ExitCode {
     let args = Args::parse();
-    args.run_main().await
+    args.run().await
 }
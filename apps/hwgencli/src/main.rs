@@ -13,7 +13,7 @@ use clap::Parser;
 pub type Args = tonic_clap_tests::HWArgs;
 
 #[tokio::main(flavor = "current_thread")]
-async fn main() -> Result<(), tonic_clap::Error> {
+async fn main() -> std::process::ExitCode {
     let args = Args::parse();
-    args.run_main().await
+    args.run().await
 }
@@ -1,6 +1,6 @@
-use heck::{ToSnakeCase, ToUpperCamelCase};
+use heck::ToUpperCamelCase;
 use proc_macro2::TokenStream;
-use prost_build::Service;
+use prost_build::{Comments, Method, Service};
 use quote::quote;
 
 pub struct ServiceGenerator {
@@ -60,14 +60,16 @@ impl CodeGenBuilder {
         for svc in services {
             let svc_name = quote::format_ident!("{}", svc.name);
             let svc_enum_name = quote::format_ident!("{}Commands", svc.name);
+            let doc = doc_attrs(&svc.comments);
             let enum_tokens = quote! {
+                    #(#doc)*
                     #[command(subcommand)]
                     #svc_name(#svc_enum_name),
             };
             svc_enum_stream.extend(enum_tokens);
 
             let svc_call_tokens = quote! {
-                Self::#svc_name(cmd) => cmd.execute(ch, json_data).await,
+                Self::#svc_name(cmd) => cmd.execute(ch, json_data, verbose).await,
             };
             svc_call_stream.extend(svc_call_tokens);
         }
@@ -78,7 +80,8 @@ impl CodeGenBuilder {
                     self,
                     ch: tonic::transport::Channel,
                     json_data: Option<String>,
-                ) -> Result<Box<dyn std::fmt::Debug>, tonic::Status> {
+                    verbose: bool,
+                ) -> Result<tonic_clap::output::Responses, tonic_clap::Error> {
                     match self {
                         #svc_call_stream
                     }
@@ -111,38 +114,58 @@ impl CodeGenBuilder {
             // it is in the outer mod.
             let input_type: syn::Path =
                 syn::parse_str(&format!("super::{}", m.input_type)).unwrap();
-            let method_name = quote::format_ident!("{}", m.name);
+            let output_type: syn::Path =
+                syn::parse_str(&format!("super::{}", m.output_type)).unwrap();
+            let path = method_path(svc, m);
 
+            let doc = doc_attrs(&m.comments);
             let enum_tokens = quote! {
-                #method_enum_val(#input_type),
+                #(#doc)*
+                #method_enum_val(tonic_clap::arg::RequestArgs<#input_type>),
             };
             method_enum_stream.extend(enum_tokens);
 
-            // For now if json data is present ignore args.
-            // TODO: implement merging of json data with args.
+            // json data is the base request, given options override it.
+            // Each JSON document is sent as a request, stopping at the first failed call.
             let method_call = quote! {
                 #svc_enum_name::#method_enum_val(val) => {
-                    let request: #input_type = match json_data {
-                        Some(data) => serde_json::from_str(&data).unwrap(),
-                        None => val,
-                    };
-                    Ok(Box::new(c.#method_name(request).await?.into_inner()))
+                    let requests = val
+                        .into_requests(json_data.as_deref())?;
+                    let mut responses = Vec::new();
+                    for request in requests {
+                        let response = tonic_clap::call::unary::<_, #output_type>(
+                            ch.clone(),
+                            #path,
+                            request,
+                            verbose,
+                        )
+                        .await;
+                        match response {
+                            Ok(response) => responses.push(response),
+                            Err(error) if responses.is_empty() => return Err(error),
+                            Err(error) => {
+                                return Err(tonic_clap::Error::Partial {
+                                    responses: tonic_clap::output::Responses(responses),
+                                    error: Box::new(error),
+                                });
+                            }
+                        }
+                    }
+                    Ok(tonic_clap::output::Responses(responses))
                 }
             };
             method_call_stream.extend(method_call);
         }
 
         // Generate execute function.
-        let client_name = quote::format_ident!("{}Client", svc.name);
-        let client_mod_name = quote::format_ident!("{}_client", svc.name.to_snake_case());
         let exe_fn = quote! {
             impl tonic_clap::arg::ExecuteCmd for #svc_enum_name {
                 async fn execute(
                     self,
                     ch: tonic::transport::Channel,
                     json_data: Option<String>,
-                ) -> Result<Box<dyn std::fmt::Debug>, tonic::Status> {
-                    let mut c = super::#client_mod_name::#client_name::new(ch);
+                    verbose: bool,
+                ) -> Result<tonic_clap::output::Responses, tonic_clap::Error> {
                     match self {
                         #method_call_stream
                     }
@@ -160,3 +183,19 @@ impl CodeGenBuilder {
         }
     }
 }
+
+/// gRPC path of the method, e.g. `/helloworld.Greeter/SayHello`.
+fn method_path(svc: &Service, m: &Method) -> String {
+    match svc.package.as_str() {
+        "" => format!("/{}/{}", svc.proto_name, m.proto_name),
+        package => format!("/{package}.{}/{}", svc.proto_name, m.proto_name),
+    }
+}
+
+/// Leading proto comments as doc attributes, which clap uses as the subcommand about.
+fn doc_attrs(comments: &Comments) -> impl Iterator<Item = TokenStream> + '_ {
+    comments
+        .leading
+        .iter()
+        .map(|line| quote! { #[doc = #line] })
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use heck::{ToLowerCamelCase, ToSnakeCase, ToUpperCamelCase};
+use prost_types::{
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorSet,
+    field_descriptor_proto::Label,
+};
+use tonic_prost_build::Config;
+
+use crate::options;
+
+/// Index of all types in a descriptor set.
+/// Names are fully qualified with a leading dot, e.g. `.helloworld.EnumOk`,
+/// which is the form used by field type names and prost attribute paths.
+pub(crate) struct DescriptorIndex<'a> {
+    messages: Vec<(String, &'a DescriptorProto)>,
+    enums: Vec<(String, &'a EnumDescriptorProto)>,
+    /// `google.api.field_behavior` by fully qualified field name.
+    field_behaviors: HashMap<String, Vec<i32>>,
+}
+
+impl<'a> DescriptorIndex<'a> {
+    pub fn new(fds: &'a FileDescriptorSet, field_behaviors: HashMap<String, Vec<i32>>) -> Self {
+        let mut index = DescriptorIndex {
+            messages: Vec::new(),
+            enums: Vec::new(),
+            field_behaviors,
+        };
+        for file in &fds.file {
+            let prefix = match file.package() {
+                "" => String::new(),
+                pkg => format!(".{pkg}"),
+            };
+            for e in &file.enum_type {
+                index.enums.push((format!("{prefix}.{}", e.name()), e));
+            }
+            for msg in &file.message_type {
+                index.add_message(&prefix, msg);
+            }
+        }
+        index
+    }
+
+    fn add_message(&mut self, prefix: &str, msg: &'a DescriptorProto) {
+        let fq_name = format!("{prefix}.{}", msg.name());
+        for e in &msg.enum_type {
+            self.enums.push((format!("{fq_name}.{}", e.name()), e));
+        }
+        for nested in &msg.nested_type {
+            self.add_message(&fq_name, nested);
+        }
+        if !is_map_entry(msg) {
+            self.messages.push((fq_name, msg));
+        }
+    }
+
+    /// Record the proto full name of each message,
+    /// so that the `TonicClap` derive registers it for `google.protobuf.Any`.
+    pub fn annotate_messages(&self, cfg: &mut Config) {
+        for (fq_name, _) in &self.messages {
+            cfg.message_attribute(
+                fq_name,
+                format!(
+                    "#[tonic_clap(message = {:?})]",
+                    fq_name.trim_start_matches('.')
+                ),
+            );
+        }
+    }
+
+    /// Attach proto metadata lost in prost code gen to the generated fields.
+    /// The `TonicClap` derive reads them back into the message schema.
+    pub fn annotate_fields(&self, cfg: &mut Config) {
+        for (fq_name, msg) in &self.messages {
+            for field in &msg.field {
+                let path = field_attribute_path(fq_name, msg, field);
+                if let Some(attr) = self.behavior_attribute(fq_name, field) {
+                    cfg.field_attribute(&path, attr);
+                }
+                if let Some(attr) = name_attribute(field) {
+                    cfg.field_attribute(&path, attr);
+                }
+            }
+        }
+    }
+
+    /// prost renames enum values, e.g. `TYPE_DOUBLE` to `Double`.
+    /// Options take the proto value names.
+    pub fn annotate_enums(&self, cfg: &mut Config) {
+        for (fq_name, e) in &self.enums {
+            for value in &e.value {
+                cfg.field_attribute(
+                    format!("{fq_name}.{}", value.name()),
+                    format!("#[tonic_clap(name = {:?})]", value.name()),
+                );
+            }
+        }
+    }
+
+    /// `google.api.field_behavior` OUTPUT_ONLY and REQUIRED, or proto2 `required`.
+    fn behavior_attribute(
+        &self,
+        fq_message_name: &str,
+        field: &FieldDescriptorProto,
+    ) -> Option<String> {
+        let behaviors = self
+            .field_behaviors
+            .get(&format!("{fq_message_name}.{}", field.name()))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let behavior = if behaviors.contains(&options::OUTPUT_ONLY) {
+            "output_only"
+        } else if behaviors.contains(&options::REQUIRED) || field.label() == Label::Required {
+            "required"
+        } else {
+            return None;
+        };
+        Some(format!("#[tonic_clap({behavior})]"))
+    }
+}
+
+/// Proto and JSON names of fields prost renames, e.g. the oneof member `one_of_1` to `OneOf1`.
+/// The `TonicClap` derive falls back to the snake_case rust name and its lowerCamelCase.
+fn name_attribute(field: &FieldDescriptorProto) -> Option<String> {
+    let name = field.name();
+    let rust_name = if is_oneof_member(field) {
+        name.to_upper_camel_case()
+    } else {
+        name.to_snake_case()
+    };
+    let proto_name = rust_name.to_snake_case();
+    let mut attrs = Vec::new();
+    if proto_name != name {
+        attrs.push(format!("proto_name = {name:?}"));
+    }
+    if let Some(json_name) = &field.json_name
+        && *json_name != name.to_lower_camel_case()
+    {
+        attrs.push(format!("json_name = {json_name:?}"));
+    }
+    (!attrs.is_empty()).then(|| format!("#[tonic_clap({})]", attrs.join(", ")))
+}
+
+fn is_oneof_member(field: &FieldDescriptorProto) -> bool {
+    field.oneof_index.is_some() && !field.proto3_optional()
+}
+
+fn is_map_entry(msg: &DescriptorProto) -> bool {
+    msg.options.as_ref().is_some_and(|o| o.map_entry())
+}
+
+/// prost looks up attributes of oneof members under the oneof name.
+/// proto3 optional fields are synthetic oneofs and stay plain fields.
+fn field_attribute_path(
+    fq_message_name: &str,
+    msg: &DescriptorProto,
+    field: &FieldDescriptorProto,
+) -> String {
+    match field.oneof_index {
+        Some(i) if is_oneof_member(field) => format!(
+            "{fq_message_name}.{}.{}",
+            msg.oneof_decl[i as usize].name(),
+            field.name()
+        ),
+        _ => format!("{fq_message_name}.{}", field.name()),
+    }
+}
@@ -1,25 +1,36 @@
-use std::{io, path::Path};
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
 
 use code_gen::ServiceGenerator;
 use tonic_prost_build::Config;
 
-use crate::multi_gen::MultiGen;
+use crate::{descriptor::DescriptorIndex, multi_gen::MultiGen};
 
 // mod client;
 mod code_gen;
+mod descriptor;
 // mod server;
 mod multi_gen;
+mod options;
 
 // code gen builder
 pub struct Builder {
     cfg: Config,
     tonic_server: bool,
+    max_flag_depth: Option<usize>,
+    flag_separator: Option<char>,
+    file_descriptor_set_path: Option<PathBuf>,
 }
 
 pub fn configure() -> Builder {
     Builder {
         cfg: Config::new(),
         tonic_server: true,
+        max_flag_depth: None,
+        flag_separator: None,
+        file_descriptor_set_path: None,
     }
 }
 
@@ -40,23 +51,43 @@ impl Builder {
 
         // default only works for message structs.
         self.cfg.message_attribute(".", "#[serde(default)]");
-        self.cfg.type_attribute(".", "#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap, bevy_reflect::Reflect)]");
-
-        // disable recusive known types
-        // See bevy issue: https://github.com/bevyengine/bevy/issues/8965
-        for message in &[
-            "google.protobuf.FileDescriptorProto",
-            "google.protobuf.DescriptorProto",
-        ] {
-            self.cfg
-                .type_attribute(message, "#[reflect(no_field_bounds)]");
-        }
-
-        self.cfg.disable_comments(["."]);
+        self.cfg.type_attribute(
+            ".",
+            "#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]",
+        );
         // self.cfg
         //     .field_attribute(".", "#[arg(long, default_value = \"\")]");
         self.cfg.compile_well_known_types();
-        self.cfg.compile_protos(protos, includes)?;
+
+        // Load descriptors first to record proto info that prost does not keep.
+        // Custom options are only in the encoded descriptors.
+        let fds_path = self.file_descriptor_set_path.clone().unwrap_or_else(|| {
+            let dir = std::env::var_os("OUT_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(std::env::temp_dir);
+            dir.join(format!("tonic-clap-{}.fds", std::process::id()))
+        });
+        self.cfg.file_descriptor_set_path(&fds_path);
+        let fds = self.cfg.load_fds(protos, includes)?;
+        let field_behaviors = options::field_behaviors(&std::fs::read(&fds_path)?)?;
+        if self.file_descriptor_set_path.is_none() {
+            std::fs::remove_file(&fds_path)?;
+        }
+        let index = DescriptorIndex::new(&fds, field_behaviors);
+        index.annotate_messages(&mut self.cfg);
+        index.annotate_fields(&mut self.cfg);
+        index.annotate_enums(&mut self.cfg);
+        if let Some(depth) = self.max_flag_depth {
+            self.cfg
+                .message_attribute(".", format!("#[tonic_clap(max_flag_depth = {depth})]"));
+        }
+        if let Some(separator) = self.flag_separator {
+            self.cfg.message_attribute(
+                ".",
+                format!("#[tonic_clap(flag_separator = {separator:?})]"),
+            );
+        }
+        self.cfg.compile_fds(fds)?;
         Ok(())
     }
 
@@ -69,6 +100,28 @@ impl Builder {
         self
     }
 
+    /// Max number of path segments in a message field flag, e.g. `--a.b.c` is 3.
+    /// Deeper and recursive messages take a single JSON flag. Defaults to 8.
+    pub fn max_flag_depth(mut self, depth: usize) -> Self {
+        self.max_flag_depth = Some(depth);
+        self
+    }
+
+    /// Separator of the path segments in a message field flag, e.g. `/` for `--a/b`.
+    /// Defaults to `.`.
+    pub fn flag_separator(mut self, separator: char) -> Self {
+        self.flag_separator = Some(separator);
+        self
+    }
+
+    /// Keep the encoded descriptor set at this path.
+    /// Use this instead of `Config::file_descriptor_set_path`, which the build overrides
+    /// to read custom options such as `google.api.field_behavior`.
+    pub fn file_descriptor_set_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.file_descriptor_set_path = Some(path.into());
+        self
+    }
+
     // turn builder into generator
     pub fn service_generator(&self) -> Box<dyn prost_build::ServiceGenerator> {
         Box::new(ServiceGenerator::new())
@@ -0,0 +1,88 @@
+//! Custom field options read from the encoded descriptor set.
+//! prost_types drops extensions, e.g. `google.api.field_behavior`,
+//! so the descriptors are decoded again into the subset below.
+
+use std::collections::HashMap;
+
+use prost::Message;
+
+/// `google.api.FieldBehavior.REQUIRED`.
+pub(crate) const REQUIRED: i32 = 2;
+/// `google.api.FieldBehavior.OUTPUT_ONLY`.
+pub(crate) const OUTPUT_ONLY: i32 = 3;
+
+#[derive(Clone, PartialEq, Message)]
+struct FileDescriptorSet {
+    #[prost(message, repeated, tag = "1")]
+    file: Vec<FileDescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct FileDescriptorProto {
+    #[prost(string, optional, tag = "2")]
+    package: Option<String>,
+    #[prost(message, repeated, tag = "4")]
+    message_type: Vec<DescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct DescriptorProto {
+    #[prost(string, optional, tag = "1")]
+    name: Option<String>,
+    #[prost(message, repeated, tag = "2")]
+    field: Vec<FieldDescriptorProto>,
+    #[prost(message, repeated, tag = "3")]
+    nested_type: Vec<DescriptorProto>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct FieldDescriptorProto {
+    #[prost(string, optional, tag = "1")]
+    name: Option<String>,
+    #[prost(message, optional, tag = "8")]
+    options: Option<FieldOptions>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct FieldOptions {
+    /// `google.api.field_behavior` extension.
+    #[prost(int32, repeated, packed = "false", tag = "1052")]
+    field_behavior: Vec<i32>,
+}
+
+/// `google.api.field_behavior` values of all fields that have any,
+/// keyed by the fully qualified field name, e.g. `.helloworld.Field1.fname`.
+pub(crate) fn field_behaviors(
+    encoded_fds: &[u8],
+) -> Result<HashMap<String, Vec<i32>>, prost::DecodeError> {
+    let fds = FileDescriptorSet::decode(encoded_fds)?;
+    let mut behaviors = HashMap::new();
+    for file in &fds.file {
+        let prefix = match file.package() {
+            "" => String::new(),
+            pkg => format!(".{pkg}"),
+        };
+        for msg in &file.message_type {
+            add_message(&mut behaviors, &prefix, msg);
+        }
+    }
+    Ok(behaviors)
+}
+
+fn add_message(behaviors: &mut HashMap<String, Vec<i32>>, prefix: &str, msg: &DescriptorProto) {
+    let fq_name = format!("{prefix}.{}", msg.name());
+    for field in &msg.field {
+        match &field.options {
+            Some(options) if !options.field_behavior.is_empty() => {
+                behaviors.insert(
+                    format!("{fq_name}.{}", field.name()),
+                    options.field_behavior.clone(),
+                );
+            }
+            _ => {}
+        }
+    }
+    for nested in &msg.nested_type {
+        add_message(behaviors, &fq_name, nested);
+    }
+}
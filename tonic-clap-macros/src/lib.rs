@@ -1,18 +1,141 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{DeriveInput, parse_macro_input};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    Data, DataEnum, DataStruct, DeriveInput, Fields, GenericArgument, Ident, LitChar, LitInt,
+    LitStr, PathArguments, Type, ext::IdentExt, parse_macro_input,
+};
 
-/// Combined derive macro that implements both Args and FromArgMatches
-#[proc_macro_derive(TonicClap)]
+/// Derives the `tonic_clap::schema` traits of a prost message, oneof or enum.
+/// Messages also implement `clap::Args` and `clap::FromArgMatches`, with one option per field.
+///
+/// Fields are read from the rust types and the `#[prost(..)]` attributes.
+/// Doc comments of fields and oneof variants become the option help. `tonic-clap-build`
+/// adds `#[tonic_clap(..)]` attributes for proto information prost does not keep:
+/// * `#[tonic_clap(message = "pkg.Msg")]` on messages also registers the type for `google.protobuf.Any`.
+/// * `#[tonic_clap(max_flag_depth = N)]` and `#[tonic_clap(flag_separator = 'c')]` on messages,
+///   see `tonic_clap::schema::MessageSchema`.
+/// * `#[tonic_clap(required)]` and `#[tonic_clap(output_only)]` on fields and oneof variants.
+/// * `#[tonic_clap(proto_name = "..", json_name = "..")]` on fields and oneof variants,
+///   when prost renames them, e.g. `one_of_1` to `OneOf1`.
+/// * `#[tonic_clap(enumeration = "Path")]` on i32 fields holding a proto enum.
+/// * `#[tonic_clap(name = "VALUE")]` on proto enum values.
+///
+/// Options are added when `augment_args` is called, so a command can flatten several
+/// `TonicClap` types. `tonic_clap::arg::RequestArgs` defers them to when clap builds
+/// the method subcommand.
+#[proc_macro_derive(TonicClap, attributes(tonic_clap))]
 pub fn derive_tonic_clap(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    let expanded = if !input.generics.params.is_empty() {
+        Err(syn::Error::new_spanned(
+            &input.generics,
+            "TonicClap does not support generics",
+        ))
+    } else {
+        match &input.data {
+            Data::Struct(data) => derive_message(&input, data),
+            Data::Enum(data) if data.variants.iter().all(|v| v.fields.is_empty()) => {
+                derive_enumeration(&input, data)
+            }
+            Data::Enum(data) => derive_oneof(&input, data),
+            Data::Union(_) => Err(syn::Error::new_spanned(
+                &input.ident,
+                "TonicClap does not support unions",
+            )),
+        }
+    };
+    expanded
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn derive_message(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStream2> {
     let name = &input.ident;
+    let Fields::Named(named) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "TonicClap messages must have named fields",
+        ));
+    };
+    let attrs = TypeAttrs::parse(input)?;
+    let fields = named
+        .named
+        .iter()
+        .map(|f| FieldInfo::parse(f.ident.as_ref().expect("named field"), &f.ty, &f.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let type_name = name.to_string();
+    let full_name = match &attrs.message {
+        Some(message) => quote! { Some(#message) },
+        None => quote! { None },
+    };
+    let max_flag_depth = match &attrs.max_flag_depth {
+        Some(depth) => quote! { Some(#depth) },
+        None => quote! { None },
+    };
+    let flag_separator = match &attrs.flag_separator {
+        Some(separator) => quote! { Some(#separator) },
+        None => quote! { None },
+    };
+    let schema_fields = fields.iter().map(FieldInfo::schema);
+    let merges = fields.iter().map(|f| {
+        let ident = &f.ident;
+        f.merge(quote! { self.#ident })
+    });
+    let register = attrs.message.map(|message_name| {
+        quote! {
+            tonic_clap::any::inventory::submit! {
+                tonic_clap::any::MessageType::new::<#name>(#message_name)
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl tonic_clap::schema::Message for #name {
+            fn schema() -> &'static tonic_clap::schema::MessageSchema {
+                static SCHEMA: tonic_clap::schema::MessageSchema =
+                    tonic_clap::schema::MessageSchema {
+                        name: #type_name,
+                        full_name: #full_name,
+                        max_flag_depth: #max_flag_depth,
+                        flag_separator: #flag_separator,
+                        fields: &[#(#schema_fields),*],
+                    };
+                &SCHEMA
+            }
+
+            fn merge_flags(
+                &mut self,
+                flags: &tonic_clap::flags::Flags<'_>,
+            ) -> ::std::result::Result<(), clap::Error> {
+                #(#merges)*
+                Ok(())
+            }
+        }
+
+        impl tonic_clap::schema::Nested for #name {
+            const KIND: tonic_clap::schema::Kind = tonic_clap::schema::Kind::Message(
+                <Self as tonic_clap::schema::Message>::schema,
+            );
+
+            fn merge_optional(
+                field: &mut ::std::option::Option<Self>,
+                flags: &tonic_clap::flags::Flags<'_>,
+                name: &str,
+            ) -> ::std::result::Result<(), clap::Error> {
+                flags.message(field, name)
+            }
+        }
 
-    let expanded = quote! {
         impl clap::Args for #name {
             fn augment_args(cmd: clap::Command) -> clap::Command {
-                use bevy_reflect::Typed;
-                tonic_clap::impl_augment_args(cmd, Self::type_info())
+                // --help still renders without the options. from_arg_matches reports the error.
+                tonic_clap::impl_augment_args(
+                    cmd.clone(),
+                    <Self as tonic_clap::schema::Message>::schema(),
+                )
+                .unwrap_or_else(|e| cmd.after_help(format!("error: {e}")))
             }
 
             fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
@@ -31,11 +154,480 @@ pub fn derive_tonic_clap(input: TokenStream) -> TokenStream {
                 &mut self,
                 matches: &clap::ArgMatches,
             ) -> ::std::result::Result<(), clap::Error> {
-                *self = tonic_clap::impl_from_arg_matches(matches)?;
+                tonic_clap::impl_update_from_arg_matches(self, matches)
+            }
+        }
+
+        #register
+    })
+}
+
+/// prost oneof enum, one tuple variant per member.
+fn derive_oneof(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let mut fields = Vec::new();
+    for variant in &data.variants {
+        let Fields::Unnamed(unnamed) = &variant.fields else {
+            return Err(oneof_error(variant));
+        };
+        let [field] = unnamed.unnamed.iter().collect::<Vec<_>>()[..] else {
+            return Err(oneof_error(variant));
+        };
+        fields.push(FieldInfo::parse(&variant.ident, &field.ty, &variant.attrs)?);
+    }
+
+    let type_name = name.to_string();
+    let schema_fields = fields.iter().map(FieldInfo::schema);
+    let merges = fields.iter().map(|f| {
+        let variant = &f.ident;
+        let field_name = &f.name;
+        let merge = f.merge(quote! { value });
+        quote! {
+            if flags.contains(#field_name) {
+                let mut value = match this.take() {
+                    Some(Self::#variant(value)) => value,
+                    _ => ::std::default::Default::default(),
+                };
+                #merge
+                *this = Some(Self::#variant(value));
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl tonic_clap::schema::Oneof for #name {
+            fn schema() -> &'static tonic_clap::schema::MessageSchema {
+                static SCHEMA: tonic_clap::schema::MessageSchema =
+                    tonic_clap::schema::MessageSchema {
+                        name: #type_name,
+                        full_name: None,
+                        max_flag_depth: None,
+                        flag_separator: None,
+                        fields: &[#(#schema_fields),*],
+                    };
+                &SCHEMA
+            }
+
+            fn merge_flags(
+                this: &mut ::std::option::Option<Self>,
+                flags: &tonic_clap::flags::Flags<'_>,
+            ) -> ::std::result::Result<(), clap::Error> {
+                #(#merges)*
                 Ok(())
             }
         }
+
+        impl tonic_clap::schema::Nested for #name {
+            const KIND: tonic_clap::schema::Kind = tonic_clap::schema::Kind::Oneof(
+                <Self as tonic_clap::schema::Oneof>::schema,
+            );
+
+            fn merge_optional(
+                field: &mut ::std::option::Option<Self>,
+                flags: &tonic_clap::flags::Flags<'_>,
+                name: &str,
+            ) -> ::std::result::Result<(), clap::Error> {
+                <Self as tonic_clap::schema::Oneof>::merge_flags(field, &flags.nested(name)?)
+            }
+        }
+    })
+}
+
+fn oneof_error(variant: &syn::Variant) -> syn::Error {
+    syn::Error::new_spanned(
+        variant,
+        "TonicClap enums are either proto enums without variant fields, \
+         or oneofs with exactly one field per variant",
+    )
+}
+
+/// prost enum. Values are named by `#[tonic_clap(name = "..")]`, or else the variant.
+fn derive_enumeration(input: &DeriveInput, data: &DataEnum) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let values = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let mut value_name = variant.ident.unraw().to_string();
+            for attr in variant
+                .attrs
+                .iter()
+                .filter(|a| a.path().is_ident("tonic_clap"))
+            {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("name") {
+                        value_name = meta.value()?.parse::<LitStr>()?.value();
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported tonic_clap attribute"))
+                    }
+                })?;
+            }
+            let ident = &variant.ident;
+            // The concrete name, as `Self` is not available in the static.
+            Ok(quote! { (#value_name, #name::#ident as i32) })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let type_name = name.to_string();
+
+    Ok(quote! {
+        impl tonic_clap::schema::Enumeration for #name {
+            fn schema() -> &'static tonic_clap::schema::EnumSchema {
+                static SCHEMA: tonic_clap::schema::EnumSchema = tonic_clap::schema::EnumSchema {
+                    name: #type_name,
+                    values: &[#(#values),*],
+                };
+                &SCHEMA
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct TypeAttrs {
+    message: Option<String>,
+    max_flag_depth: Option<LitInt>,
+    flag_separator: Option<LitChar>,
+}
+
+impl TypeAttrs {
+    /// prost applies attributes of a message to its nested messages too,
+    /// so the most specific, i.e. longest, proto name wins.
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut names = Vec::new();
+        let mut attrs = TypeAttrs::default();
+        for attr in input
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("tonic_clap"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("message") {
+                    names.push(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("max_flag_depth") {
+                    attrs.max_flag_depth = Some(meta.value()?.parse::<LitInt>()?);
+                    Ok(())
+                } else if meta.path.is_ident("flag_separator") {
+                    attrs.flag_separator = Some(meta.value()?.parse::<LitChar>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported tonic_clap attribute"))
+                }
+            })?;
+        }
+        attrs.message = names.into_iter().max_by_key(|n| n.len());
+        Ok(attrs)
+    }
+}
+
+/// A struct field or oneof variant.
+struct FieldInfo {
+    /// Field or variant ident.
+    ident: Ident,
+    /// Name of the option, without `r#`.
+    name: String,
+    label: Label,
+    value: Value,
+    /// `Box<T>` or `Option<Box<T>>`, as prost generates for recursive messages.
+    boxed: bool,
+    behavior: Option<&'static str>,
+    proto_name: Option<LitStr>,
+    json_name: Option<LitStr>,
+    /// Doc comment, which prost generates from the proto comment.
+    doc: Option<String>,
+}
+
+enum Label {
+    Singular,
+    Optional,
+    Repeated,
+    Map(&'static str),
+}
+
+enum Value {
+    /// `tonic_clap::schema::Scalar` variant.
+    Scalar(&'static str),
+    Enum(syn::Path),
+    /// Message or oneof.
+    Nested(Type),
+}
+
+impl FieldInfo {
+    fn parse(ident: &Ident, ty: &Type, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut enumeration = None;
+        let mut required = false;
+        let mut output_only = false;
+        let mut proto_name = None;
+        let mut json_name = None;
+        let mut doc = Vec::new();
+        for attr in attrs {
+            if let syn::Meta::NameValue(nv) = &attr.meta
+                && nv.path.is_ident("doc")
+                && let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(line),
+                    ..
+                }) = &nv.value
+            {
+                doc.push(line.value());
+            } else if attr.path().is_ident("prost") {
+                attr.parse_nested_meta(|meta| {
+                    let value = if meta.input.peek(syn::Token![=]) {
+                        Some(meta.value()?.parse::<syn::Lit>()?)
+                    } else {
+                        None
+                    };
+                    match value {
+                        Some(syn::Lit::Str(s)) if meta.path.is_ident("enumeration") => {
+                            enumeration = Some(s.parse::<syn::Path>()?);
+                        }
+                        // e.g. "string, enumeration(EnumOk)".
+                        Some(syn::Lit::Str(s)) if meta.path.is_ident("map") => {
+                            let map = s.value();
+                            let value = map.split_once(',').map_or("", |(_, v)| v.trim());
+                            if let Some(path) = value
+                                .strip_prefix("enumeration(")
+                                .and_then(|v| v.strip_suffix(')'))
+                            {
+                                enumeration = Some(syn::parse_str::<syn::Path>(path)?);
+                            }
+                        }
+                        None if meta.path.is_ident("required") => required = true,
+                        _ => {}
+                    }
+                    Ok(())
+                })?;
+            } else if attr.path().is_ident("tonic_clap") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("required") {
+                        required = true;
+                    } else if meta.path.is_ident("output_only") {
+                        output_only = true;
+                    } else if meta.path.is_ident("enumeration") {
+                        enumeration = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    } else if meta.path.is_ident("proto_name") {
+                        proto_name = Some(meta.value()?.parse::<LitStr>()?);
+                    } else if meta.path.is_ident("json_name") {
+                        json_name = Some(meta.value()?.parse::<LitStr>()?);
+                    } else {
+                        return Err(meta.error("unsupported tonic_clap attribute"));
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+
+        let (label, inner, boxed) = classify(ty)?;
+        let value = match (scalar(inner), enumeration) {
+            (Some("I32"), Some(path)) => Value::Enum(path),
+            (_, Some(_)) => {
+                return Err(syn::Error::new_spanned(ty, "proto enum fields must be i32"));
+            }
+            (Some(s), None) => Value::Scalar(s),
+            (None, None) => Value::Nested(inner.clone()),
+        };
+        let behavior = if output_only {
+            Some("OutputOnly")
+        } else if required {
+            Some("Required")
+        } else {
+            None
+        };
+        Ok(Self {
+            ident: ident.clone(),
+            name: ident.unraw().to_string(),
+            label,
+            value,
+            boxed,
+            behavior,
+            proto_name,
+            json_name,
+            doc: doc_text(&doc),
+        })
+    }
+
+    /// `tonic_clap::schema::Field` initializer.
+    fn schema(&self) -> TokenStream2 {
+        let name = &self.name;
+        let kind = match &self.value {
+            Value::Scalar(s) => {
+                let s = format_ident!("{s}");
+                quote! { tonic_clap::schema::Kind::Scalar(tonic_clap::schema::Scalar::#s) }
+            }
+            Value::Enum(path) => quote! {
+                tonic_clap::schema::Kind::Enum(<#path as tonic_clap::schema::Enumeration>::schema)
+            },
+            Value::Nested(ty) => quote! { <#ty as tonic_clap::schema::Nested>::KIND },
+        };
+        let label = match self.label {
+            Label::Singular => quote! { tonic_clap::schema::Label::Singular },
+            Label::Optional => quote! { tonic_clap::schema::Label::Optional },
+            Label::Repeated => quote! { tonic_clap::schema::Label::Repeated },
+            Label::Map(key) => {
+                let key = format_ident!("{key}");
+                quote! { tonic_clap::schema::Label::Map(tonic_clap::schema::Scalar::#key) }
+            }
+        };
+        let behavior = match self.behavior {
+            Some(b) => {
+                let b = format_ident!("{b}");
+                quote! { Some(tonic_clap::schema::FieldBehavior::#b) }
+            }
+            None => quote! { None },
+        };
+        let some = |name: &Option<LitStr>| match name {
+            Some(name) => quote! { Some(#name) },
+            None => quote! { None },
+        };
+        let (proto_name, json_name) = (some(&self.proto_name), some(&self.json_name));
+        let doc = match &self.doc {
+            Some(doc) => quote! { Some(#doc) },
+            None => quote! { None },
+        };
+        quote! {
+            tonic_clap::schema::Field {
+                name: #name,
+                kind: #kind,
+                label: #label,
+                behavior: #behavior,
+                proto_name: #proto_name,
+                json_name: #json_name,
+                doc: #doc,
+            }
+        }
+    }
+
+    /// Statement setting `target` from the flags of this field.
+    fn merge(&self, target: TokenStream2) -> TokenStream2 {
+        let name = &self.name;
+        match (&self.label, &self.value) {
+            (Label::Singular, Value::Nested(_)) if self.boxed => quote! {
+                flags.required_message(&mut *#target, #name)?;
+            },
+            (Label::Singular, Value::Nested(_)) => quote! {
+                flags.required_message(&mut #target, #name)?;
+            },
+            (Label::Optional, Value::Nested(ty)) if self.boxed => quote! {
+                let mut unboxed = #target.take().map(|v| *v);
+                <#ty as tonic_clap::schema::Nested>::merge_optional(&mut unboxed, flags, #name)?;
+                #target = unboxed.map(::std::boxed::Box::new);
+            },
+            (Label::Optional, Value::Nested(ty)) => quote! {
+                <#ty as tonic_clap::schema::Nested>::merge_optional(&mut #target, flags, #name)?;
+            },
+            (Label::Singular, _) => quote! { flags.scalar(&mut #target, #name)?; },
+            (Label::Optional, _) => quote! { flags.optional(&mut #target, #name)?; },
+            (Label::Repeated, Value::Nested(_)) | (Label::Map(_), _) => quote! {
+                flags.json(&mut #target, #name)?;
+            },
+            (Label::Repeated, _) => quote! { flags.repeated(&mut #target, #name)?; },
+        }
+    }
+}
+
+/// Text of `///` lines. prost escapes brackets for rustdoc, which help does not need.
+fn doc_text(lines: &[String]) -> Option<String> {
+    let text = lines
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .replace("\\[", "[")
+        .replace("\\]", "]");
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Label and element type of a field type, and whether the element is boxed.
+fn classify(ty: &Type) -> syn::Result<(Label, &Type, bool)> {
+    if let Some(inner) = generic_arg(ty, "Option") {
+        return Ok(match generic_arg(inner, "Box") {
+            Some(inner) => (Label::Optional, inner, true),
+            None => (Label::Optional, inner, false),
+        });
+    }
+    if let Some(inner) = generic_arg(ty, "Box") {
+        return Ok((Label::Singular, inner, true));
+    }
+    if scalar(ty).is_none()
+        && let Some(inner) = generic_arg(ty, "Vec")
+    {
+        return Ok((Label::Repeated, inner, false));
+    }
+    if let Some([key, value]) = generic_args(ty, "HashMap").or_else(|| generic_args(ty, "BTreeMap"))
+    {
+        let key = scalar(key)
+            .ok_or_else(|| syn::Error::new_spanned(key, "map keys must be proto scalars"))?;
+        return Ok((Label::Map(key), value, false));
+    }
+    Ok((Label::Singular, ty, false))
+}
+
+/// `tonic_clap::schema::Scalar` variant of a rust type.
+fn scalar(ty: &Type) -> Option<&'static str> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let scalar = match segment.ident.to_string().as_str() {
+        "String" => "String",
+        "bool" => "Bool",
+        "i32" => "I32",
+        "i64" => "I64",
+        "u32" => "U32",
+        "u64" => "U64",
+        "f32" => "F32",
+        "f64" => "F64",
+        // prost `bytes = "bytes"` generates `::prost::bytes::Bytes`.
+        "Bytes" if is_bytes_path(&path.path) => "Bytes",
+        "Vec"
+            if generic_arg(ty, "Vec")
+                .is_some_and(|t| matches!(t, Type::Path(p) if p.path.is_ident("u8"))) =>
+        {
+            "Bytes"
+        }
+        _ => return None,
     };
+    Some(scalar)
+}
 
-    TokenStream::from(expanded)
+/// `prost::bytes::Bytes` or `bytes::Bytes`, with or without a leading `::`.
+fn is_bytes_path(path: &syn::Path) -> bool {
+    let names = path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>();
+    let names = names.iter().map(String::as_str).collect::<Vec<_>>();
+    matches!(names[..], ["prost", "bytes", "Bytes"] | ["bytes", "Bytes"])
+}
+
+/// `T` of a type path ending with `wrapper<T>`.
+fn generic_arg<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    match generic_args::<1>(ty, wrapper)? {
+        [inner] => Some(inner),
+    }
+}
+
+/// Type arguments of a type path ending with `wrapper<..>`.
+fn generic_args<'a, const N: usize>(ty: &'a Type, wrapper: &str) -> Option<[&'a Type; N]> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let types = args
+        .args
+        .iter()
+        .filter_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    types.try_into().ok()
 }
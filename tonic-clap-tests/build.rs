@@ -14,10 +14,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let proto_file = Path::new("../protos/helloworld.proto");
     let proto_dir = proto_file.parent().unwrap();
+    let field_types_file = Path::new("../protos/fieldtypes.proto");
+    let legacy_file = Path::new("../protos/legacy.proto");
     assert!(proto_file.exists());
+    assert!(field_types_file.exists());
+    assert!(legacy_file.exists());
     assert!(proto_dir.exists());
     assert!(path.exists());
-    builder.compile(&[proto_file], &[proto_dir]).unwrap();
+    builder
+        .compile(&[proto_file, field_types_file, legacy_file], &[proto_dir])
+        .unwrap();
 
     // do a tonic build for server codegen.
     tonic_prost_build::configure()
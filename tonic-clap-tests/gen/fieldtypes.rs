@@ -0,0 +1,219 @@
+// This file is @generated by prost-build.
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.TimeRequest")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeRequest {
+    #[prost(message, optional, tag = "1")]
+    pub created_at: ::core::option::Option<super::google::protobuf::Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub timeout: ::core::option::Option<super::google::protobuf::Duration>,
+    #[prost(message, repeated, tag = "3")]
+    pub history: ::prost::alloc::vec::Vec<super::google::protobuf::Timestamp>,
+}
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.AnyRequest")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AnyRequest {
+    #[prost(message, optional, tag = "1")]
+    pub detail: ::core::option::Option<super::google::protobuf::Any>,
+    #[prost(message, repeated, tag = "2")]
+    pub details: ::prost::alloc::vec::Vec<super::google::protobuf::Any>,
+    #[prost(map = "string, string", tag = "3")]
+    pub labels: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    #[prost(message, optional, tag = "4")]
+    pub spec: ::core::option::Option<super::google::protobuf::Struct>,
+}
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.Label")]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Label {
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub nested: ::core::option::Option<label::Nested>,
+}
+/// Nested message and enum types in `Label`.
+pub mod label {
+    #[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+    #[serde(default)]
+    #[tonic_clap(message = "fieldtypes.Label.Nested")]
+    #[tonic_clap(message = "fieldtypes.Label")]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+    pub struct Nested {
+        #[prost(message, optional, tag = "1")]
+        pub inner: ::core::option::Option<super::super::google::protobuf::Any>,
+    }
+}
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.WrapperRequest")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WrapperRequest {
+    #[prost(message, optional, tag = "1")]
+    pub name: ::core::option::Option<super::google::protobuf::StringValue>,
+    #[prost(message, optional, tag = "2")]
+    pub offset: ::core::option::Option<super::google::protobuf::Int64Value>,
+    #[prost(message, optional, tag = "3")]
+    pub enabled: ::core::option::Option<super::google::protobuf::BoolValue>,
+    #[prost(message, optional, tag = "4")]
+    pub token: ::core::option::Option<super::google::protobuf::BytesValue>,
+    #[prost(message, optional, tag = "5")]
+    pub ratio: ::core::option::Option<super::google::protobuf::DoubleValue>,
+    #[prost(map = "string, message", tag = "6")]
+    pub limits: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        super::google::protobuf::UInt32Value,
+    >,
+    #[prost(map = "string, message", tag = "7")]
+    pub blobs: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        super::google::protobuf::BytesValue,
+    >,
+}
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.StructRequest")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StructRequest {
+    #[prost(message, optional, tag = "1")]
+    pub spec: ::core::option::Option<super::google::protobuf::Struct>,
+    #[prost(message, optional, tag = "2")]
+    pub value: ::core::option::Option<super::google::protobuf::Value>,
+    #[prost(message, optional, tag = "3")]
+    pub items: ::core::option::Option<super::google::protobuf::ListValue>,
+    #[prost(message, repeated, tag = "4")]
+    pub specs: ::prost::alloc::vec::Vec<super::google::protobuf::Struct>,
+}
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.TreeNode")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TreeNode {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub children: ::prost::alloc::vec::Vec<TreeNode>,
+    #[prost(map = "string, message", tag = "3")]
+    pub by_name: ::std::collections::HashMap<::prost::alloc::string::String, TreeNode>,
+    #[prost(message, optional, tag = "4")]
+    pub leaf: ::core::option::Option<Leaf>,
+}
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.Leaf")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Leaf {
+    #[prost(string, tag = "1")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub subtrees: ::prost::alloc::vec::Vec<TreeNode>,
+}
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.TreeRequest")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TreeRequest {
+    #[prost(message, optional, tag = "1")]
+    pub root: ::core::option::Option<TreeNode>,
+    #[prost(message, optional, tag = "2")]
+    pub leaf: ::core::option::Option<Leaf>,
+}
+/// prost boxes singular fields leading back to their own message.
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.Expr")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Expr {
+    #[prost(string, tag = "1")]
+    pub op: ::prost::alloc::string::String,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub left: ::core::option::Option<::prost::alloc::boxed::Box<Expr>>,
+    #[prost(message, optional, boxed, tag = "3")]
+    pub right: ::core::option::Option<::prost::alloc::boxed::Box<Expr>>,
+    #[prost(message, optional, boxed, tag = "4")]
+    pub operand: ::core::option::Option<::prost::alloc::boxed::Box<Operand>>,
+    #[prost(oneof = "expr::Group", tags = "5, 6")]
+    pub group: ::core::option::Option<expr::Group>,
+}
+/// Nested message and enum types in `Expr`.
+pub mod expr {
+    #[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Group {
+        #[prost(message, tag = "5")]
+        Nested(::prost::alloc::boxed::Box<super::Expr>),
+        #[prost(string, tag = "6")]
+        Literal(::prost::alloc::string::String),
+    }
+}
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.Operand")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Operand {
+    #[prost(int64, tag = "1")]
+    pub value: i64,
+    #[prost(message, optional, boxed, tag = "2")]
+    pub parent: ::core::option::Option<::prost::alloc::boxed::Box<Expr>>,
+    #[prost(message, optional, tag = "3")]
+    pub detail: ::core::option::Option<super::google::protobuf::Any>,
+}
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.ExprRequest")]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExprRequest {
+    #[prost(message, optional, tag = "1")]
+    pub expr: ::core::option::Option<Expr>,
+    #[prost(string, tag = "2")]
+    pub label: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.BehaviorRequest")]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BehaviorRequest {
+    #[prost(string, tag = "1")]
+    #[tonic_clap(required)]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    #[tonic_clap(output_only)]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    #[tonic_clap(required)]
+    pub label: ::core::option::Option<Label>,
+    #[prost(string, repeated, tag = "4")]
+    #[tonic_clap(required)]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "5")]
+    pub note: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "fieldtypes.OneofRequest")]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct OneofRequest {
+    #[prost(string, tag = "3")]
+    pub comment: ::prost::alloc::string::String,
+    #[prost(oneof = "oneof_request::Target", tags = "1, 2")]
+    pub target: ::core::option::Option<oneof_request::Target>,
+}
+/// Nested message and enum types in `OneofRequest`.
+pub mod oneof_request {
+    #[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Target {
+        #[prost(message, tag = "1")]
+        Label(super::Label),
+        #[prost(string, tag = "2")]
+        Name(::prost::alloc::string::String),
+    }
+}
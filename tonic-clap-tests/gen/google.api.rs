@@ -0,0 +1,60 @@
+// This file is @generated by prost-build.
+/// An indicator of the behavior of a given field (for example, that a field
+/// is required in requests, or given as output but ignored as input).
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FieldBehavior {
+    #[tonic_clap(name = "FIELD_BEHAVIOR_UNSPECIFIED")]
+    Unspecified = 0,
+    #[tonic_clap(name = "OPTIONAL")]
+    Optional = 1,
+    #[tonic_clap(name = "REQUIRED")]
+    Required = 2,
+    #[tonic_clap(name = "OUTPUT_ONLY")]
+    OutputOnly = 3,
+    #[tonic_clap(name = "INPUT_ONLY")]
+    InputOnly = 4,
+    #[tonic_clap(name = "IMMUTABLE")]
+    Immutable = 5,
+    #[tonic_clap(name = "UNORDERED_LIST")]
+    UnorderedList = 6,
+    #[tonic_clap(name = "NON_EMPTY_DEFAULT")]
+    NonEmptyDefault = 7,
+    #[tonic_clap(name = "IDENTIFIER")]
+    Identifier = 8,
+}
+impl FieldBehavior {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "FIELD_BEHAVIOR_UNSPECIFIED",
+            Self::Optional => "OPTIONAL",
+            Self::Required => "REQUIRED",
+            Self::OutputOnly => "OUTPUT_ONLY",
+            Self::InputOnly => "INPUT_ONLY",
+            Self::Immutable => "IMMUTABLE",
+            Self::UnorderedList => "UNORDERED_LIST",
+            Self::NonEmptyDefault => "NON_EMPTY_DEFAULT",
+            Self::Identifier => "IDENTIFIER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FIELD_BEHAVIOR_UNSPECIFIED" => Some(Self::Unspecified),
+            "OPTIONAL" => Some(Self::Optional),
+            "REQUIRED" => Some(Self::Required),
+            "OUTPUT_ONLY" => Some(Self::OutputOnly),
+            "INPUT_ONLY" => Some(Self::InputOnly),
+            "IMMUTABLE" => Some(Self::Immutable),
+            "UNORDERED_LIST" => Some(Self::UnorderedList),
+            "NON_EMPTY_DEFAULT" => Some(Self::NonEmptyDefault),
+            "IDENTIFIER" => Some(Self::Identifier),
+            _ => None,
+        }
+    }
+}
@@ -1,23 +1,19 @@
 // This file is @generated by prost-build.
-#[derive(
-    serde::Serialize,
-    serde::Deserialize,
-    tonic_clap::TonicClap,
-    bevy_reflect::Reflect
-)]
+/// The request message containing the user's name.
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
 #[serde(default)]
+#[tonic_clap(message = "helloworld.HelloRequest")]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct HelloRequest {
+    /// Name of the person to greet.
+    ///
+    /// Shown in the greeting as is.
     #[prost(string, tag = "1")]
     pub name: ::prost::alloc::string::String,
 }
-#[derive(
-    serde::Serialize,
-    serde::Deserialize,
-    tonic_clap::TonicClap,
-    bevy_reflect::Reflect
-)]
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
 #[serde(default)]
+#[tonic_clap(message = "helloworld.HelloRequest2")]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct HelloRequest2 {
     #[prost(string, tag = "1")]
@@ -37,53 +33,39 @@ pub struct HelloRequest2 {
 }
 /// Nested message and enum types in `HelloRequest2`.
 pub mod hello_request2 {
-    #[derive(
-        serde::Serialize,
-        serde::Deserialize,
-        tonic_clap::TonicClap,
-        bevy_reflect::Reflect
-    )]
+    #[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
     #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
     pub enum OneOfField {
         #[prost(message, tag = "6")]
+        #[tonic_clap(proto_name = "one_of_1")]
         OneOf1(super::OneOf1),
         #[prost(message, tag = "7")]
+        #[tonic_clap(proto_name = "one_of_2")]
         OneOf2(super::OneOf2),
+        /// Internal one of field cannot be optional.
         #[prost(int32, tag = "8")]
         OneOfInt(i32),
     }
 }
-#[derive(
-    serde::Serialize,
-    serde::Deserialize,
-    tonic_clap::TonicClap,
-    bevy_reflect::Reflect
-)]
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
 #[serde(default)]
+#[tonic_clap(message = "helloworld.OneOf1")]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct OneOf1 {
     #[prost(string, tag = "1")]
     pub one_of_str: ::prost::alloc::string::String,
 }
-#[derive(
-    serde::Serialize,
-    serde::Deserialize,
-    tonic_clap::TonicClap,
-    bevy_reflect::Reflect
-)]
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
 #[serde(default)]
+#[tonic_clap(message = "helloworld.OneOf2")]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct OneOf2 {
     #[prost(int32, tag = "1")]
     pub one_of_int: i32,
 }
-#[derive(
-    serde::Serialize,
-    serde::Deserialize,
-    tonic_clap::TonicClap,
-    bevy_reflect::Reflect
-)]
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
 #[serde(default)]
+#[tonic_clap(message = "helloworld.Field1")]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct Field1 {
     #[prost(string, tag = "1")]
@@ -91,40 +73,30 @@ pub struct Field1 {
     #[prost(int32, tag = "2")]
     pub fcount: i32,
 }
-#[derive(
-    serde::Serialize,
-    serde::Deserialize,
-    tonic_clap::TonicClap,
-    bevy_reflect::Reflect
-)]
+/// The response message containing the greetings
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
 #[serde(default)]
+#[tonic_clap(message = "helloworld.HelloReply")]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct HelloReply {
     #[prost(string, tag = "1")]
     pub message: ::prost::alloc::string::String,
 }
-#[derive(
-    serde::Serialize,
-    serde::Deserialize,
-    tonic_clap::TonicClap,
-    bevy_reflect::Reflect
-)]
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
 #[serde(default)]
+#[tonic_clap(message = "helloworld.HelloReply2")]
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct HelloReply2 {
     #[prost(string, tag = "1")]
     pub message: ::prost::alloc::string::String,
 }
-#[derive(
-    serde::Serialize,
-    serde::Deserialize,
-    tonic_clap::TonicClap,
-    bevy_reflect::Reflect
-)]
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum EnumOk {
+    #[tonic_clap(name = "Ok0")]
     Ok0 = 0,
+    #[tonic_clap(name = "Ok1")]
     Ok1 = 1,
 }
 impl EnumOk {
@@ -444,6 +416,7 @@ pub mod greeter2_client {
 pub mod cli {
     #[derive(clap::Subcommand, Debug)]
     pub enum CommandServices {
+        ///The greeting service definition.
         #[command(subcommand)]
         Greeter(GreeterCommands),
         #[command(subcommand)]
@@ -454,77 +427,155 @@ pub mod cli {
             self,
             ch: tonic::transport::Channel,
             json_data: Option<String>,
-        ) -> Result<Box<dyn std::fmt::Debug>, tonic::Status> {
+            verbose: bool,
+        ) -> Result<tonic_clap::output::Responses, tonic_clap::Error> {
             match self {
-                Self::Greeter(cmd) => cmd.execute(ch, json_data).await,
-                Self::Greeter2(cmd) => cmd.execute(ch, json_data).await,
+                Self::Greeter(cmd) => cmd.execute(ch, json_data, verbose).await,
+                Self::Greeter2(cmd) => cmd.execute(ch, json_data, verbose).await,
             }
         }
     }
     #[derive(clap::Subcommand, Debug)]
     pub enum GreeterCommands {
-        SayHello(super::HelloRequest),
-        SayHello2(super::HelloRequest2),
+        ///Sends a greeting
+        SayHello(tonic_clap::arg::RequestArgs<super::HelloRequest>),
+        SayHello2(tonic_clap::arg::RequestArgs<super::HelloRequest2>),
     }
     impl tonic_clap::arg::ExecuteCmd for GreeterCommands {
         async fn execute(
             self,
             ch: tonic::transport::Channel,
             json_data: Option<String>,
-        ) -> Result<Box<dyn std::fmt::Debug>, tonic::Status> {
-            let mut c = super::greeter_client::GreeterClient::new(ch);
+            verbose: bool,
+        ) -> Result<tonic_clap::output::Responses, tonic_clap::Error> {
             match self {
                 GreeterCommands::SayHello(val) => {
-                    let request: super::HelloRequest = match json_data {
-                        Some(data) => serde_json::from_str(&data).unwrap(),
-                        None => val,
-                    };
-                    Ok(Box::new(c.say_hello(request).await?.into_inner()))
+                    let requests = val.into_requests(json_data.as_deref())?;
+                    let mut responses = Vec::new();
+                    for request in requests {
+                        let response = tonic_clap::call::unary::<
+                            _,
+                            super::HelloReply,
+                        >(ch.clone(), "/helloworld.Greeter/SayHello", request, verbose)
+                            .await;
+                        match response {
+                            Ok(response) => responses.push(response),
+                            Err(error) if responses.is_empty() => return Err(error),
+                            Err(error) => {
+                                return Err(tonic_clap::Error::Partial {
+                                    responses: tonic_clap::output::Responses(responses),
+                                    error: Box::new(error),
+                                });
+                            }
+                        }
+                    }
+                    Ok(tonic_clap::output::Responses(responses))
                 }
                 GreeterCommands::SayHello2(val) => {
-                    let request: super::HelloRequest2 = match json_data {
-                        Some(data) => serde_json::from_str(&data).unwrap(),
-                        None => val,
-                    };
-                    Ok(Box::new(c.say_hello2(request).await?.into_inner()))
+                    let requests = val.into_requests(json_data.as_deref())?;
+                    let mut responses = Vec::new();
+                    for request in requests {
+                        let response = tonic_clap::call::unary::<
+                            _,
+                            super::HelloReply2,
+                        >(ch.clone(), "/helloworld.Greeter/SayHello2", request, verbose)
+                            .await;
+                        match response {
+                            Ok(response) => responses.push(response),
+                            Err(error) if responses.is_empty() => return Err(error),
+                            Err(error) => {
+                                return Err(tonic_clap::Error::Partial {
+                                    responses: tonic_clap::output::Responses(responses),
+                                    error: Box::new(error),
+                                });
+                            }
+                        }
+                    }
+                    Ok(tonic_clap::output::Responses(responses))
                 }
             }
         }
     }
     #[derive(clap::Subcommand, Debug)]
     pub enum Greeter2Commands {
-        SayHello(super::HelloRequest),
-        SayHello2(super::HelloRequest2),
-        SayHello3(super::super::google::protobuf::Empty),
+        ///Sends a greeting
+        SayHello(tonic_clap::arg::RequestArgs<super::HelloRequest>),
+        SayHello2(tonic_clap::arg::RequestArgs<super::HelloRequest2>),
+        SayHello3(tonic_clap::arg::RequestArgs<super::super::google::protobuf::Empty>),
     }
     impl tonic_clap::arg::ExecuteCmd for Greeter2Commands {
         async fn execute(
             self,
             ch: tonic::transport::Channel,
             json_data: Option<String>,
-        ) -> Result<Box<dyn std::fmt::Debug>, tonic::Status> {
-            let mut c = super::greeter2_client::Greeter2Client::new(ch);
+            verbose: bool,
+        ) -> Result<tonic_clap::output::Responses, tonic_clap::Error> {
             match self {
                 Greeter2Commands::SayHello(val) => {
-                    let request: super::HelloRequest = match json_data {
-                        Some(data) => serde_json::from_str(&data).unwrap(),
-                        None => val,
-                    };
-                    Ok(Box::new(c.say_hello(request).await?.into_inner()))
+                    let requests = val.into_requests(json_data.as_deref())?;
+                    let mut responses = Vec::new();
+                    for request in requests {
+                        let response = tonic_clap::call::unary::<
+                            _,
+                            super::HelloReply,
+                        >(ch.clone(), "/helloworld.Greeter2/SayHello", request, verbose)
+                            .await;
+                        match response {
+                            Ok(response) => responses.push(response),
+                            Err(error) if responses.is_empty() => return Err(error),
+                            Err(error) => {
+                                return Err(tonic_clap::Error::Partial {
+                                    responses: tonic_clap::output::Responses(responses),
+                                    error: Box::new(error),
+                                });
+                            }
+                        }
+                    }
+                    Ok(tonic_clap::output::Responses(responses))
                 }
                 Greeter2Commands::SayHello2(val) => {
-                    let request: super::HelloRequest2 = match json_data {
-                        Some(data) => serde_json::from_str(&data).unwrap(),
-                        None => val,
-                    };
-                    Ok(Box::new(c.say_hello2(request).await?.into_inner()))
+                    let requests = val.into_requests(json_data.as_deref())?;
+                    let mut responses = Vec::new();
+                    for request in requests {
+                        let response = tonic_clap::call::unary::<
+                            _,
+                            super::HelloReply2,
+                        >(ch.clone(), "/helloworld.Greeter2/SayHello2", request, verbose)
+                            .await;
+                        match response {
+                            Ok(response) => responses.push(response),
+                            Err(error) if responses.is_empty() => return Err(error),
+                            Err(error) => {
+                                return Err(tonic_clap::Error::Partial {
+                                    responses: tonic_clap::output::Responses(responses),
+                                    error: Box::new(error),
+                                });
+                            }
+                        }
+                    }
+                    Ok(tonic_clap::output::Responses(responses))
                 }
                 Greeter2Commands::SayHello3(val) => {
-                    let request: super::super::google::protobuf::Empty = match json_data {
-                        Some(data) => serde_json::from_str(&data).unwrap(),
-                        None => val,
-                    };
-                    Ok(Box::new(c.say_hello3(request).await?.into_inner()))
+                    let requests = val.into_requests(json_data.as_deref())?;
+                    let mut responses = Vec::new();
+                    for request in requests {
+                        let response = tonic_clap::call::unary::<
+                            _,
+                            super::HelloReply,
+                        >(ch.clone(), "/helloworld.Greeter2/SayHello3", request, verbose)
+                            .await;
+                        match response {
+                            Ok(response) => responses.push(response),
+                            Err(error) if responses.is_empty() => return Err(error),
+                            Err(error) => {
+                                return Err(tonic_clap::Error::Partial {
+                                    responses: tonic_clap::output::Responses(responses),
+                                    error: Box::new(error),
+                                });
+                            }
+                        }
+                    }
+                    Ok(tonic_clap::output::Responses(responses))
                 }
             }
         }
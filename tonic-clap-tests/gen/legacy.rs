@@ -0,0 +1,12 @@
+// This file is @generated by prost-build.
+#[derive(serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+#[serde(default)]
+#[tonic_clap(message = "legacy.LegacyRequest")]
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LegacyRequest {
+    #[prost(string, required, tag = "1")]
+    #[tonic_clap(required)]
+    pub name: ::prost::alloc::string::String,
+    #[prost(int32, optional, tag = "2")]
+    pub count: ::core::option::Option<i32>,
+}
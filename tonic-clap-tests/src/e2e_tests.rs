@@ -39,6 +39,16 @@ async fn run_client_gen(addr: SocketAddr, more_args: &[&str]) {
 const CARGO_ARGS: &[&str] = &["run", "--quiet", "--bin"];
 
 async fn run_client(addr: SocketAddr, more_args: &[&str], bin: &str) {
+    run_client_exit(addr, more_args, bin, 0).await;
+}
+
+// run the cli, expecting it to exit with `code`. Returns its stdout and stderr.
+async fn run_client_exit(
+    addr: SocketAddr,
+    more_args: &[&str],
+    bin: &str,
+    code: i32,
+) -> (String, String) {
     use std::process::Stdio;
     use tokio::process::Command;
     let url = &format!("http://{addr}");
@@ -57,19 +67,24 @@ async fn run_client(addr: SocketAddr, more_args: &[&str], bin: &str) {
 
     if cfg!(feature = "llvm-cov-mode") {
         // Do not build exe using cargo, since it takes a long time and no coverage info.
-        return;
+        return Default::default();
     }
 
-    let mut child = Command::new("cargo")
+    let output = Command::new("cargo")
         .current_dir("../") // workspace dir.
         .args(&cargo_args)
         .args(&app_args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .await
         .expect("fail to spawn");
-    let ec = child.wait().await.unwrap();
-    assert!(ec.success());
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    print!("{stdout}");
+    eprint!("{stderr}");
+    assert_eq!(output.status.code(), Some(code));
+    (stdout, stderr)
 }
 
 #[tokio::test]
@@ -134,6 +149,85 @@ async fn server_test() {
     )
     .await;
 
+    run_client_gen(
+        addr,
+        &[
+            "--json-data",
+            r#"{ "name": "doc1" } { "name": "doc2" }"#,
+            "greeter",
+            "say-hello",
+        ],
+    )
+    .await;
+
+    for format in ["json", "yaml", "table", "debug"] {
+        run_client_gen(
+            addr,
+            &[
+                "--output",
+                format,
+                "--json-data",
+                r#"{ "name": "doc1" } { "name": "doc2" }"#,
+                "greeter",
+                "say-hello",
+            ],
+        )
+        .await;
+    }
+    run_client_manual(
+        addr,
+        &["-o", "json", "greeter", "say-hello", "--name", "n1"],
+    )
+    .await;
+    run_client_gen(
+        addr,
+        &[
+            "--query",
+            ".message",
+            "--json-data",
+            r#"{ "name": "doc1" } { "name": "doc2" }"#,
+            "greeter",
+            "say-hello",
+        ],
+    )
+    .await;
+    // The call goes to stderr, stdout keeps only the response.
+    for bin in ["hwgencli", "hwcli"] {
+        let (stdout, stderr) = run_client_exit(
+            addr,
+            &["--verbose", "greeter", "say-hello", "--name", "verbose"],
+            bin,
+            0,
+        )
+        .await;
+        if cfg!(feature = "llvm-cov-mode") {
+            continue;
+        }
+        assert_eq!(stdout, "{\n  \"message\": \"Hello verbose!\"\n}\n");
+        for line in [
+            "* connected in ",
+            "> /helloworld.Greeter/SayHello\n",
+            "* headers\n< content-type: application/grpc\n",
+            "* trailers\n< grpc-status: 0\n",
+            "* status OK in ",
+        ] {
+            assert!(stderr.contains(line), "{bin} {line}: {stderr}");
+        }
+    }
+
+    run_client_gen(
+        addr,
+        &[
+            "--json-data",
+            r#"{ "name": "n", "field1": { "fname": "f" } }"#,
+            "greeter2",
+            "say-hello2",
+            "--field1.fcount",
+            "2",
+        ],
+    )
+    .await;
+
     run_client_gen(
         addr,
         &[
@@ -160,16 +254,62 @@ async fn server_test() {
             "f2",
             "--field3",
             "1",
-            "--opt_string",
+            "--opt-string",
             "optstring",
-            "--one_of_field.OneOfInt",
+            "--one-of-field.one-of-int",
             "123",
         ],
     )
     .await;
 
+    // Invalid request data is a usage error, no call is made.
+    let (_, stderr) = run_client_exit(
+        addr,
+        &["--json-data", r#"{ "nmae": "n" }"#, "greeter", "say-hello"],
+        "hwgencli",
+        2,
+    )
+    .await;
+    assert!(!stderr.contains("INVALID_ARGUMENT"), "{stderr}");
+    if !cfg!(feature = "llvm-cov-mode") {
+        assert!(stderr.contains("unknown field 'nmae'"), "{stderr}");
+    }
+
+    // 64 + NOT_FOUND. The response to the first document is still printed.
+    let (stdout, stderr) = run_client_exit(
+        addr,
+        &[
+            "--json-data",
+            r#"{"name": "a"} {"name": "missing"} {"name": "b"}"#,
+            "greeter",
+            "say-hello",
+        ],
+        "hwgencli",
+        69,
+    )
+    .await;
+    if !cfg!(feature = "llvm-cov-mode") {
+        assert!(stdout.contains("Hello a!"), "{stdout}");
+        assert!(!stdout.contains("Hello b!"), "{stdout}");
+        assert!(
+            stderr.contains("NOT_FOUND: no greeting for missing"),
+            "{stderr}"
+        );
+    }
+
     token.cancel();
     svh.await.expect("task panic");
+
+    // 64 + UNAVAILABLE once the server is gone, with --verbose too.
+    for args in [
+        &["greeter", "say-hello"][..],
+        &["--verbose", "greeter", "say-hello"],
+    ] {
+        let (_, stderr) = run_client_exit(addr, args, "hwgencli", 78).await;
+        if !cfg!(feature = "llvm-cov-mode") {
+            assert!(stderr.contains("UNAVAILABLE: "), "{stderr}");
+        }
+    }
 }
 
 // ensures the ctr can show hwlp message
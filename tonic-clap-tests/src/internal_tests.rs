@@ -1,7 +1,6 @@
-// Example demonstrating pure reflection-based field setting without hardcoded field names
+// Example demonstrating schema-based field setting without hardcoded field names
 
 use clap::{Args, Command, FromArgMatches};
-use tonic_clap::{impl_augment_args, impl_from_arg_matches};
 
 use crate::helloworld::{self, EnumOk, Field1, HelloRequest2};
 
@@ -80,9 +79,9 @@ fn test_reflection_based_parsing() {
             "item2",
             "--field3",
             "0",
-            "--one_of_field.OneOf1.one_of_str",
+            "--one-of-field.one-of1.one-of-str",
             "one_of_str_value",
-            "--opt_u64",
+            "--opt-u64",
             "42",
         ])
         .unwrap();
@@ -133,7 +132,7 @@ fn test_internal_one_of_field() {
     {
         let matches = cmd
             .clone()
-            .try_get_matches_from(vec!["test", "--one_of_field.OneOfInt", "123"])
+            .try_get_matches_from(vec!["test", "--one-of-field.one-of-int", "123"])
             .unwrap();
 
         let hello_req = HelloRequest2::from_arg_matches(&matches).unwrap();
@@ -145,7 +144,7 @@ fn test_internal_one_of_field() {
     {
         let matches = cmd
             .clone()
-            .try_get_matches_from(vec!["test", "--one_of_field.OneOf2.one_of_int", "234"])
+            .try_get_matches_from(vec!["test", "--one-of-field.one-of2.one-of-int", "234"])
             .unwrap();
 
         let hello_req = HelloRequest2::from_arg_matches(&matches).unwrap();
@@ -158,20 +157,340 @@ fn test_internal_one_of_field() {
     }
     // test conflicts
     {
-        let matches = cmd
+        let e = cmd
             .clone()
             .try_get_matches_from(vec![
                 "test",
-                "--one_of_field.OneOf2.one_of_int",
+                "--one-of-field.one-of2.one-of-int",
                 "234",
-                "--one_of_field.OneOfInt",
+                "--one-of-field.one-of-int",
                 "123",
             ])
-            .unwrap();
-
-        let e = HelloRequest2::from_arg_matches(&matches).unwrap_err();
+            .unwrap_err();
         assert_eq!(e.kind(), clap::error::ErrorKind::ArgumentConflict);
+        let e = e.to_string();
+        assert!(e.contains("--one-of-field.one-of2.one-of-int"), "{e}");
+        assert!(e.contains("--one-of-field.one-of-int"), "{e}");
+    }
+}
+
+#[test]
+fn test_oneof_group() {
+    use crate::fieldtypes::{OneofRequest, oneof_request::Target};
+
+    let cmd = OneofRequest::augment_args(Command::new("test"));
+    let help = cmd.clone().render_help().to_string();
+    assert!(help.contains("Oneof target:"), "{help}");
+
+    // Flags of the same variant can be combined.
+    let matches = cmd
+        .clone()
+        .try_get_matches_from([
+            "test",
+            "--target.label.key",
+            "k",
+            "--target.label.value",
+            "v",
+            "--comment",
+            "c",
+        ])
+        .unwrap();
+    let req = OneofRequest::from_arg_matches(&matches).unwrap();
+    let Some(Target::Label(label)) = req.target else {
+        panic!("unexpected target {:?}", req.target);
+    };
+    assert_eq!((label.key.as_str(), label.value.as_str()), ("k", "v"));
+    assert_eq!(req.comment, "c");
+
+    let e = cmd
+        .try_get_matches_from(["test", "--target.label.key", "k", "--target.name", "n"])
+        .unwrap_err();
+    assert_eq!(e.kind(), clap::error::ErrorKind::ArgumentConflict);
+}
+
+#[test]
+fn test_flag_names() {
+    let cmd = HelloRequest2::augment_args(Command::new("test"));
+    let help = cmd.clone().render_help().to_string();
+    assert!(help.contains("--opt-string <OPT_STRING>"), "{help}");
+    assert!(help.contains("--one-of-field.one-of1.one-of-str"), "{help}");
+    assert!(help.contains("Oneof one-of-field:"), "{help}");
+    // oneof variants are UpperCamelCase in rust.
+    assert!(
+        help.contains("--one-of-field.one-of-int <ONE_OF_INT>"),
+        "{help}"
+    );
+    // aliases are hidden.
+    assert!(!help.contains("opt_string,"), "{help}");
+    assert!(!help.contains("optString"), "{help}");
+
+    // snake_case, the rust names and proto JSON camelCase still parse.
+    for args in [
+        [
+            "test",
+            "--opt_string",
+            "s",
+            "--one_of_field.one_of2.one_of_int",
+            "1",
+        ],
+        [
+            "test",
+            "--opt_string",
+            "s",
+            "--one_of_field.OneOf2.one_of_int",
+            "1",
+        ],
+        [
+            "test",
+            "--optString",
+            "s",
+            "--oneOfField.oneOf2.oneOfInt",
+            "1",
+        ],
+    ] {
+        let matches = cmd.clone().try_get_matches_from(args).unwrap();
+        let req = HelloRequest2::from_arg_matches(&matches).unwrap();
+        assert_eq!(req.opt_string.as_deref(), Some("s"));
+        assert_eq!(
+            req.one_of_field,
+            Some(helloworld::hello_request2::OneOfField::OneOf2(
+                helloworld::OneOf2 { one_of_int: 1 }
+            ))
+        );
+    }
+
+    // errors of flag values name the kebab-case option.
+    #[derive(Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+    #[tonic_clap(flag_separator = '/')]
+    struct Upload {
+        file_info: Option<FileInfo>,
+    }
+    #[derive(Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+    struct FileInfo {
+        file_data: Vec<u8>,
+    }
+    let cmd = Upload::augment_args(Command::new("test"));
+    let help = cmd.clone().render_help().to_string();
+    assert!(help.contains("--file-info/file-data <FILE_DATA>"), "{help}");
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["test", "--fileInfo/fileData", "@/nonexistent"])
+        .unwrap();
+    let e = Upload::from_arg_matches(&matches).err().unwrap();
+    assert!(
+        e.to_string()
+            .contains("--file-info/file-data: cannot read file"),
+        "{e}"
+    );
+}
+
+#[test]
+fn test_proto_comments() {
+    use clap::Subcommand;
+
+    let mut cmd = helloworld::HelloRequest::augment_args(Command::new("test"));
+    let help = cmd.render_help().to_string();
+    assert!(
+        help.contains("--name <NAME>  Name of the person to greet. [Arg: String]"),
+        "{help}"
+    );
+    let long_help = cmd.render_long_help().to_string();
+    assert!(
+        long_help.contains("Shown in the greeting as is."),
+        "{long_help}"
+    );
+    assert!(long_help.contains("Arg: String"), "{long_help}");
+
+    let help = HelloRequest2::augment_args(Command::new("test"))
+        .render_help()
+        .to_string();
+    assert!(
+        help.contains("Internal one of field cannot be optional. [Arg: i32]"),
+        "{help}"
+    );
+
+    // service and method comments are the subcommand about, clap drops the final period.
+    let cmd = helloworld::cli::CommandServices::augment_subcommands(Command::new("test"));
+    let greeter = cmd.find_subcommand("greeter").unwrap();
+    assert_eq!(
+        greeter.get_about().unwrap().to_string(),
+        "The greeting service definition"
+    );
+    let say_hello = greeter.find_subcommand("say-hello").unwrap();
+    assert_eq!(
+        say_hello.get_about().unwrap().to_string(),
+        "Sends a greeting"
+    );
+}
+
+#[test]
+fn test_json_data_merge() {
+    use tonic_clap::arg::RequestArgs;
+
+    let json = r#"{ "name": "json", "field1": { "fname": "f", "fcount": 1 }, "field2": ["a"] }"#;
+    let cmd = RequestArgs::<HelloRequest2>::augment_args(Command::new("test"));
+    let parse = |args: &[&str]| {
+        let matches = cmd.clone().try_get_matches_from(args).unwrap();
+        RequestArgs::<HelloRequest2>::from_arg_matches(&matches).unwrap()
+    };
+
+    // Given options override the json, nested messages are merged.
+    let req = parse(&["test", "--field1.fcount", "3", "--field2", "b"])
+        .into_requests(Some(json))
+        .unwrap()
+        .remove(0);
+    assert_eq!(req.name, "json");
+    let field1 = req.field1.unwrap();
+    assert_eq!((field1.fname.as_str(), field1.fcount), ("f", 3));
+    assert_eq!(req.field2, vec!["b"]);
+
+    // Options not given keep the json values.
+    let req = parse(&["test"])
+        .into_requests(Some(json))
+        .unwrap()
+        .remove(0);
+    assert_eq!(req.name, "json");
+    assert_eq!(req.field1.unwrap().fcount, 1);
+
+    let req = parse(&["test", "--name", "flag"])
+        .into_requests(None)
+        .unwrap()
+        .remove(0);
+    assert_eq!(req.name, "flag");
+    assert!(req.field1.is_none());
+}
+
+#[test]
+fn test_json_data_documents() {
+    use tonic_clap::arg::{CommonArgs, RequestArgs};
+
+    let cmd = RequestArgs::<HelloRequest2>::augment_args(Command::new("test"));
+    let matches = cmd
+        .try_get_matches_from(["test", "--name", "flag"])
+        .unwrap();
+    let args = || RequestArgs::<HelloRequest2>::from_arg_matches(&matches).unwrap();
+
+    // One request per document, each with the options applied.
+    let data = "{\"field2\": [\"a\"]}\n{\"field2\": [\"b\"]} {}";
+    let reqs = args().into_requests(Some(data)).unwrap();
+    assert_eq!(reqs.len(), 3);
+    assert!(reqs.iter().all(|r| r.name == "flag"));
+    assert_eq!(reqs[1].field2, vec!["b"]);
+
+    // Errors name the position in the data.
+    let e = args()
+        .into_requests(Some("{}\n{\"name\": 1}"))
+        .unwrap_err()
+        .to_string();
+    assert!(e.contains("line 2 column"), "{e}");
+    let e = args()
+        .into_requests(Some("{}\n{\"name\": "))
+        .unwrap_err()
+        .to_string();
+    assert!(e.contains("line 2 column"), "{e}");
+    assert!(args().into_requests(Some(" ")).is_err());
+
+    // Files, `@<FILE>` and `--data-file` read the same.
+    let path = std::env::temp_dir().join(format!(
+        "tonic_clap_test_json_data-{}.json",
+        std::process::id()
+    ));
+    std::fs::write(&path, r#"{"name": "file"}"#).unwrap();
+    let path = path.display().to_string();
+    let cmd = CommonArgs::augment_args(Command::new("test"));
+    for args in [
+        vec!["test", "--json-data", &format!("@{path}")],
+        vec!["test", "--data-file", &path],
+    ] {
+        let matches = cmd.clone().try_get_matches_from(args).unwrap();
+        let common = CommonArgs::from_arg_matches(&matches).unwrap();
+        assert_eq!(
+            common.read_json_data().unwrap().as_deref(),
+            Some(r#"{"name": "file"}"#)
+        );
+    }
+    std::fs::remove_file(&path).unwrap();
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["test", "--data-file", &path])
+        .unwrap();
+    let e = CommonArgs::from_arg_matches(&matches)
+        .unwrap()
+        .read_json_data()
+        .unwrap_err();
+    assert!(e.to_string().contains("cannot read file"), "{e}");
+
+    let e = cmd
+        .try_get_matches_from(["test", "--json-data", "{}", "--data-file", &path])
+        .unwrap_err();
+    assert_eq!(e.kind(), clap::error::ErrorKind::ArgumentConflict);
+}
+
+#[test]
+fn test_proto_json() {
+    use crate::fieldtypes::{TimeRequest, WrapperRequest};
+    use helloworld::hello_request2::OneOfField;
+    use tonic_clap::{json::from_json_str, output::Response};
+
+    // json names, proto names, enum names and int64 strings are accepted.
+    let req = from_json_str::<HelloRequest2>(
+        r#"{"name": "n", "field3": "Ok1", "oneOf1": {"one_of_str": "s"}, "optU64": "42"}"#,
+    )
+    .unwrap();
+    assert_eq!(req.field3, EnumOk::Ok1 as i32);
+    assert_eq!(req.opt_u64, Some(42));
+    assert!(matches!(req.one_of_field, Some(OneOfField::OneOf1(ref o)) if o.one_of_str == "s"));
+    let req = from_json_str::<HelloRequest2>(r#"{"field3": 1, "oneOfInt": 3}"#).unwrap();
+    assert_eq!(req.field3, 1);
+    assert_eq!(req.one_of_field, Some(OneOfField::OneOfInt(3)));
+
+    for (data, error) in [
+        (r#"{"nmae": "n"}"#, "nmae"),
+        (r#"{"oneOf1": {}, "oneOfInt": 3}"#, "oneof"),
+        (r#"{"field3": "Ok9"}"#, "Ok9"),
+    ] {
+        let e = from_json_str::<HelloRequest2>(data)
+            .unwrap_err()
+            .to_string();
+        assert!(e.contains(error), "{e}");
     }
+
+    // Responses print the canonical forms, and read back the same.
+    let data = r#"{"createdAt": "2024-02-29T11:00:00.500Z", "timeout": "1.5s", "history": ["1970-01-01T00:00:00Z"]}"#;
+    let req = from_json_str::<TimeRequest>(data).unwrap();
+    assert_eq!(req.timeout.unwrap().nanos, 500_000_000);
+    let json = Response::new(&req).unwrap().json;
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "createdAt": "2024-02-29T11:00:00.500Z",
+            "timeout": "1.500s",
+            "history": ["1970-01-01T00:00:00Z"],
+        })
+    );
+    assert_eq!(
+        from_json_str::<TimeRequest>(&json.to_string()).unwrap(),
+        req
+    );
+
+    let data = r#"{"offset": "-5", "token": "AQI=", "limits": {"cpu": 2}, "enabled": false}"#;
+    let req = from_json_str::<WrapperRequest>(data).unwrap();
+    assert_eq!(req.offset.unwrap().value, -5);
+    assert_eq!(req.token.clone().unwrap().value, vec![1, 2]);
+    let json = Response::new(&req).unwrap().json;
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "offset": "-5",
+            "enabled": false,
+            "token": "AQI=",
+            "limits": {"cpu": 2},
+        })
+    );
+    assert_eq!(
+        from_json_str::<WrapperRequest>(&json.to_string()).unwrap(),
+        req
+    );
 }
 
 #[test]
@@ -179,55 +498,30 @@ fn test_multi_level_nesting() {
     println!("=== Testing Multi-Level Nesting ===");
 
     // Create a test structure with 3+ levels of nesting
-    #[derive(serde::Serialize, serde::Deserialize, bevy_reflect::Reflect)]
+    #[derive(Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
     struct Level3 {
         name: String,
         value: i32,
     }
 
-    #[derive(serde::Serialize, serde::Deserialize, bevy_reflect::Reflect)]
+    #[derive(Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
     struct Level2 {
         level3: Option<Level3>,
         count: i32,
     }
 
-    #[derive(serde::Serialize, serde::Deserialize, bevy_reflect::Reflect)]
+    #[derive(Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
     struct Level1 {
         level2: Option<Level2>,
         id: String,
     }
 
-    #[derive(serde::Serialize, serde::Deserialize, bevy_reflect::Reflect)]
+    #[derive(Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
     struct MultiLevelStruct {
         level1: Option<Level1>,
         top: String,
     }
 
-    impl clap::Args for MultiLevelStruct {
-        fn augment_args(cmd: clap::Command) -> clap::Command {
-            use bevy_reflect::Typed;
-            impl_augment_args(cmd, Self::type_info())
-        }
-        fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
-            Self::augment_args(cmd)
-        }
-    }
-
-    impl clap::FromArgMatches for MultiLevelStruct {
-        fn from_arg_matches(
-            matches: &clap::ArgMatches,
-        ) -> ::std::result::Result<Self, clap::Error> {
-            impl_from_arg_matches(matches)
-        }
-        fn update_from_arg_matches(
-            &mut self,
-            matches: &clap::ArgMatches,
-        ) -> ::std::result::Result<(), clap::Error> {
-            *self = impl_from_arg_matches(matches)?;
-            Ok(())
-        }
-    }
-
     // Test with deep nesting: top -> level1 -> level2 -> level3 -> name/value
     let cmd = MultiLevelStruct::augment_args(clap::Command::new("test"));
     let matches = cmd
@@ -299,3 +593,719 @@ fn test_enum_json() {
         "one_of_str_value"
     );
 }
+
+#[test]
+fn test_enum_field() {
+    let cmd = HelloRequest2::augment_args(Command::new("test"));
+    // proto value name
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["test", "--field3", "Ok1"])
+        .unwrap();
+    let request = HelloRequest2::from_arg_matches(&matches).unwrap();
+    assert_eq!(request.field3, EnumOk::Ok1 as i32);
+
+    // numeric value still works
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["test", "--field3", "1"])
+        .unwrap();
+    let request = HelloRequest2::from_arg_matches(&matches).unwrap();
+    assert_eq!(request.field3, EnumOk::Ok1 as i32);
+
+    // unknown names and numbers are rejected
+    for bad in ["Ok2", "2"] {
+        let e = cmd
+            .clone()
+            .try_get_matches_from(["test", "--field3", bad])
+            .unwrap_err();
+        assert_eq!(e.kind(), clap::error::ErrorKind::InvalidValue);
+    }
+
+    // only names are listed in help
+    let mut cmd = cmd;
+    // options are added when the command is built.
+    cmd.build();
+    let arg = cmd
+        .get_arguments()
+        .find(|a| a.get_id() == "field3")
+        .unwrap();
+    let names = arg
+        .get_possible_values()
+        .iter()
+        .filter(|v| !v.is_hide_set())
+        .map(|v| v.get_name().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Ok0", "Ok1"]);
+}
+
+#[test]
+fn test_bytes_field() {
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+    #[serde(default)]
+    struct BytesMsg {
+        data: Vec<u8>,
+        opt_data: Option<Vec<u8>>,
+        files: std::collections::HashMap<String, Vec<u8>>,
+    }
+
+    let cmd = BytesMsg::augment_args(Command::new("test"));
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["test", "--data", "aGVsbG8=", "--opt_data", "hex:0aff"])
+        .unwrap();
+    let msg = BytesMsg::from_arg_matches(&matches).unwrap();
+    assert_eq!(msg.data, b"hello");
+    assert_eq!(msg.opt_data, Some(vec![0x0a, 0xff]));
+
+    // file contents
+    let path = std::env::temp_dir().join(format!(
+        "tonic_clap_test_bytes_field-{}.bin",
+        std::process::id()
+    ));
+    std::fs::write(&path, [1u8, 2, 3]).unwrap();
+    let file_arg = format!("@{}", path.display());
+    let entry_arg = format!("a={file_arg}");
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["test", "--data", &file_arg, "--files", &entry_arg])
+        .unwrap();
+    let msg = BytesMsg::from_arg_matches(&matches).unwrap();
+    assert_eq!(msg.data, vec![1, 2, 3]);
+    assert_eq!(msg.opt_data, None);
+    assert_eq!(msg.files["a"], vec![1, 2, 3]);
+
+    // the file is read when the message is built, not while parsing.
+    std::fs::remove_file(path).unwrap();
+    let e = BytesMsg::from_arg_matches(&matches).unwrap_err();
+    assert_eq!(e.kind(), clap::error::ErrorKind::ValueValidation);
+    assert!(e.to_string().contains("--data: cannot read file"), "{e}");
+
+    let e = cmd
+        .try_get_matches_from(["test", "--data", "hex:xyz"])
+        .unwrap_err();
+    assert_eq!(e.kind(), clap::error::ErrorKind::ValueValidation);
+}
+
+#[test]
+fn test_map_field() {
+    use std::collections::HashMap;
+
+    #[derive(Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+    #[serde(default)]
+    struct MapValue {
+        name: String,
+        count: i32,
+    }
+
+    #[derive(Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+    #[serde(default)]
+    struct MapMsg {
+        labels: HashMap<String, String>,
+        counts: HashMap<u32, i64>,
+        #[tonic_clap(enumeration = "EnumOk")]
+        states: HashMap<String, i32>,
+        values: HashMap<String, MapValue>,
+    }
+
+    let cmd = MapMsg::augment_args(Command::new("test"));
+    let matches = cmd
+        .clone()
+        .try_get_matches_from([
+            "test",
+            "--labels",
+            "a=1",
+            "--labels",
+            "b=x=y",
+            "--counts",
+            "7=-8",
+            "--states",
+            "s=Ok1",
+            "--values",
+            r#"v={"name": "n", "count": 3}"#,
+        ])
+        .unwrap();
+    let msg = MapMsg::from_arg_matches(&matches).unwrap();
+    assert_eq!(msg.labels.len(), 2);
+    assert_eq!(msg.labels["a"], "1");
+    // only the first '=' separates the key.
+    assert_eq!(msg.labels["b"], "x=y");
+    assert_eq!(msg.counts[&7], -8);
+    assert_eq!(msg.states["s"], EnumOk::Ok1 as i32);
+    assert_eq!(msg.values["v"].name, "n");
+    assert_eq!(msg.values["v"].count, 3);
+
+    for bad in [
+        ["--labels", "no_separator"],
+        ["--counts", "x=1"],
+        ["--states", "s=Ok2"],
+        ["--values", "v={bad json"],
+    ] {
+        let e = cmd
+            .clone()
+            .try_get_matches_from(["test", bad[0], bad[1]])
+            .unwrap_err();
+        assert_eq!(e.kind(), clap::error::ErrorKind::ValueValidation);
+    }
+}
+
+#[test]
+fn test_repeated_message_field() {
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+    #[serde(default)]
+    struct Mount {
+        source: String,
+        options: Vec<String>,
+    }
+
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+    #[serde(default)]
+    struct RepeatedMsg {
+        mounts: Vec<Mount>,
+        field1: Vec<helloworld::Field1>,
+    }
+
+    let cmd = RepeatedMsg::augment_args(Command::new("test"));
+    let matches = cmd
+        .clone()
+        .try_get_matches_from([
+            "test",
+            "--mounts",
+            r#"{"source": "/a", "options": ["ro"]}"#,
+            "--mounts",
+            r#"{"source": "/b"}"#,
+            "--field1",
+            r#"{"fname": "f", "fcount": 2}"#,
+        ])
+        .unwrap();
+    let msg = RepeatedMsg::from_arg_matches(&matches).unwrap();
+    assert_eq!(msg.mounts.len(), 2);
+    assert_eq!(msg.mounts[0].source, "/a");
+    assert_eq!(msg.mounts[0].options, vec!["ro"]);
+    assert_eq!(msg.mounts[1].source, "/b");
+    assert!(msg.mounts[1].options.is_empty());
+    assert_eq!(
+        msg.field1,
+        vec![helloworld::Field1 {
+            fname: "f".to_string(),
+            fcount: 2
+        }]
+    );
+
+    // element must be a valid json value
+    let e = cmd
+        .clone()
+        .try_get_matches_from(["test", "--mounts", "{"])
+        .unwrap_err();
+    assert_eq!(e.kind(), clap::error::ErrorKind::ValueValidation);
+    // element must match the message
+    let matches = cmd
+        .try_get_matches_from(["test", "--mounts", r#"{"source": 1}"#])
+        .unwrap();
+    let e = RepeatedMsg::from_arg_matches(&matches).unwrap_err();
+    assert_eq!(e.kind(), clap::error::ErrorKind::ValueValidation);
+}
+
+#[test]
+fn test_repeated_scalar_field() {
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize, tonic_clap::TonicClap)]
+    #[serde(default)]
+    struct ScalarsMsg {
+        ids: Vec<i32>,
+        sizes: Vec<u64>,
+        flags: Vec<bool>,
+        ratios: Vec<f64>,
+        names: Vec<String>,
+        blobs: Vec<Vec<u8>>,
+        #[tonic_clap(enumeration = "EnumOk")]
+        states: Vec<i32>,
+    }
+
+    let cmd = ScalarsMsg::augment_args(Command::new("test"));
+    let matches = cmd
+        .clone()
+        .try_get_matches_from([
+            "test",
+            "--ids",
+            "1,2",
+            "--ids",
+            "-3",
+            "--sizes",
+            "18446744073709551615",
+            "--flags",
+            "true,false",
+            "--ratios",
+            "0.5",
+            "--ratios",
+            "2",
+            "--names",
+            "a,b",
+            "--names",
+            "c",
+            "--blobs",
+            "hex:01",
+            "--blobs",
+            "AgM=",
+            "--states",
+            "Ok1,0",
+        ])
+        .unwrap();
+    let msg = ScalarsMsg::from_arg_matches(&matches).unwrap();
+    assert_eq!(msg.ids, vec![1, 2, -3]);
+    assert_eq!(msg.sizes, vec![u64::MAX]);
+    assert_eq!(msg.flags, vec![true, false]);
+    assert_eq!(msg.ratios, vec![0.5, 2.0]);
+    // strings are never split on commas
+    assert_eq!(msg.names, vec!["a,b", "c"]);
+    assert_eq!(msg.blobs, vec![vec![1], vec![2, 3]]);
+    assert_eq!(msg.states, vec![EnumOk::Ok1 as i32, EnumOk::Ok0 as i32]);
+
+    let e = cmd
+        .try_get_matches_from(["test", "--ids", "1,x"])
+        .unwrap_err();
+    assert_eq!(e.kind(), clap::error::ErrorKind::ValueValidation);
+}
+
+#[test]
+fn test_well_known_time_fields() {
+    use crate::fieldtypes::TimeRequest;
+
+    let cmd = TimeRequest::augment_args(Command::new("test"));
+    let matches = cmd
+        .clone()
+        .try_get_matches_from([
+            "test",
+            "--created_at",
+            "2024-02-29T12:00:00.5+01:00",
+            "--timeout",
+            "1h30m",
+            "--history",
+            "1970-01-01T00:00:00Z",
+            "--history",
+            "now-5m",
+        ])
+        .unwrap();
+    let req = TimeRequest::from_arg_matches(&matches).unwrap();
+    let created_at = req.created_at.unwrap();
+    assert_eq!(
+        (created_at.seconds, created_at.nanos),
+        (1709204400, 500_000_000)
+    );
+    let timeout = req.timeout.unwrap();
+    assert_eq!((timeout.seconds, timeout.nanos), (5400, 0));
+    assert_eq!(req.history.len(), 2);
+    assert_eq!(req.history[0].seconds, 0);
+    assert!(req.history[1].seconds > 0);
+
+    let matches = cmd.clone().try_get_matches_from(["test"]).unwrap();
+    let req = TimeRequest::from_arg_matches(&matches).unwrap();
+    assert!(req.created_at.is_none() && req.timeout.is_none());
+
+    let err = cmd
+        .clone()
+        .try_get_matches_from(["test", "--timeout", "soon"])
+        .unwrap_err();
+    assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+    let err = cmd
+        .try_get_matches_from(["test", "--created_at", "2024-13-01T00:00:00Z"])
+        .unwrap_err();
+    assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+}
+
+#[test]
+fn test_any_field() {
+    use crate::fieldtypes::{AnyRequest, Label};
+    use prost::Message;
+
+    let cmd = AnyRequest::augment_args(Command::new("test"));
+    let matches = cmd
+        .clone()
+        .try_get_matches_from([
+            "test",
+            "--detail",
+            r#"{"@type": "type.googleapis.com/fieldtypes.Label", "key": "k1", "value": "v1"}"#,
+            "--details",
+            r#"{"@type": "type.googleapis.com/helloworld.Field1", "fname": "f1"}"#,
+            "--details",
+            r#"{"@type": "type.googleapis.com/fieldtypes.Label", "key": "k2",
+                "nested": {"inner": {"@type": "type.googleapis.com/google.protobuf.Duration", "seconds": 3}}}"#,
+        ])
+        .unwrap();
+    let req = AnyRequest::from_arg_matches(&matches).unwrap();
+    let detail = req.detail.clone().unwrap();
+    assert_eq!(detail.type_url, "type.googleapis.com/fieldtypes.Label");
+    let label = Label::decode(detail.value.as_slice()).unwrap();
+    assert_eq!((label.key.as_str(), label.value.as_str()), ("k1", "v1"));
+    assert_eq!(req.details.len(), 2);
+    let field1 = helloworld::Field1::decode(req.details[0].value.as_slice()).unwrap();
+    assert_eq!(field1.fname, "f1");
+    let label = Label::decode(req.details[1].value.as_slice()).unwrap();
+    let inner = label.nested.unwrap().inner.unwrap();
+    assert_eq!(
+        inner.type_url,
+        "type.googleapis.com/google.protobuf.Duration"
+    );
+
+    // Responses print the unpacked message, well known types in a value field.
+    let resp = tonic_clap::output::Response::new(&req).unwrap();
+    assert_eq!(
+        resp.json["detail"],
+        serde_json::json!({"@type": "type.googleapis.com/fieldtypes.Label", "key": "k1", "value": "v1"})
+    );
+    assert_eq!(
+        resp.json["details"][1]["nested"]["inner"],
+        serde_json::json!({"@type": "type.googleapis.com/google.protobuf.Duration", "value": "3s"})
+    );
+
+    // json data packs Any the same way.
+    let req2: AnyRequest = tonic_clap::json::from_json_str(
+        r#"{"detail": {"@type": "fieldtypes.Label", "key": "k1", "value": "v1"}}"#,
+    )
+    .unwrap();
+    assert_eq!(req2.detail.unwrap().value, detail.value);
+
+    let err = cmd
+        .clone()
+        .try_get_matches_from(["test", "--detail", r#"{"@type": "pkg.Unknown"}"#])
+        .and_then(|m| AnyRequest::from_arg_matches(&m))
+        .unwrap_err();
+    assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("unknown Any type 'pkg.Unknown'"));
+
+    // `@type` is a plain key outside of Any fields.
+    let matches = cmd
+        .try_get_matches_from([
+            "test",
+            "--labels",
+            "@type=x",
+            "--spec",
+            r#"{"@type": "x", "inner": {"@type": "y"}}"#,
+        ])
+        .unwrap();
+    let req = AnyRequest::from_arg_matches(&matches).unwrap();
+    assert_eq!(req.labels["@type"], "x");
+    assert!(req.spec.unwrap().fields.contains_key("@type"));
+    let req: AnyRequest = tonic_clap::json::from_json_str(
+        r#"{"labels": {"@type": "x"}, "spec": {"@type": "x"}, "detail": {"@type": "fieldtypes.Label", "key": "@type"}}"#,
+    )
+    .unwrap();
+    assert_eq!(req.labels["@type"], "x");
+    let label = Label::decode(req.detail.unwrap().value.as_slice()).unwrap();
+    assert_eq!(label.key, "@type");
+}
+
+#[test]
+fn test_wrapper_field() {
+    use crate::fieldtypes::WrapperRequest;
+
+    let cmd = WrapperRequest::augment_args(Command::new("test"));
+    let help = cmd.clone().render_help().to_string();
+    assert!(help.contains("--offset <OFFSET>"), "{help}");
+    assert!(!help.contains("--offset.value"), "{help}");
+
+    let path = std::env::temp_dir().join(format!(
+        "tonic_clap_test_wrapper_bytes-{}.bin",
+        std::process::id()
+    ));
+    std::fs::write(&path, [7u8, 8]).unwrap();
+    let blob_file = format!("file=@{}", path.display());
+
+    let matches = cmd
+        .clone()
+        .try_get_matches_from([
+            "test",
+            "--name",
+            "",
+            "--offset",
+            "-5",
+            "--enabled",
+            "false",
+            "--token",
+            "hex:0102",
+            "--limits",
+            "cpu=2",
+            "--blobs",
+            "inline=hex:0102",
+            "--blobs",
+            &blob_file,
+        ])
+        .unwrap();
+    let req = WrapperRequest::from_arg_matches(&matches).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    // Passing the flag sets the wrapper, even to the default value.
+    assert_eq!(req.name.unwrap().value, "");
+    assert_eq!(req.offset.unwrap().value, -5);
+    assert!(!req.enabled.unwrap().value);
+    assert_eq!(req.token.unwrap().value, vec![1, 2]);
+    assert!(req.ratio.is_none());
+    assert_eq!(req.limits["cpu"].value, 2);
+    // Wrapped bytes map values read `@<FILE>` like plain bytes.
+    assert_eq!(req.blobs["inline"].value, vec![1, 2]);
+    assert_eq!(req.blobs["file"].value, vec![7, 8]);
+
+    let matches = cmd.try_get_matches_from(["test"]).unwrap();
+    let req = WrapperRequest::from_arg_matches(&matches).unwrap();
+    assert!(req.name.is_none() && req.offset.is_none() && req.enabled.is_none());
+}
+
+#[test]
+fn test_struct_field() {
+    use crate::fieldtypes::StructRequest;
+    use crate::google::protobuf::value::Kind;
+
+    let dir = std::env::temp_dir().join(format!(
+        "tonic_clap_test_struct_field-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("spec.json");
+    std::fs::write(&file, r#"{"from_file": [1, 2]}"#).unwrap();
+    let file_arg = format!("@{}", file.display());
+
+    let cmd = StructRequest::augment_args(Command::new("test"));
+    let matches = cmd
+        .clone()
+        .try_get_matches_from([
+            "test",
+            "--spec",
+            r#"{"name": "n1", "nested": {"ok": true}, "none": null}"#,
+            "--value",
+            r#""text""#,
+            "--items",
+            r#"[1, "two"]"#,
+            "--specs",
+            &file_arg,
+        ])
+        .unwrap();
+    let req = StructRequest::from_arg_matches(&matches).unwrap();
+    let spec = req.spec.clone().unwrap();
+    assert_eq!(
+        spec.fields["name"].kind,
+        Some(Kind::StringValue("n1".into()))
+    );
+    assert_eq!(spec.fields["none"].kind, Some(Kind::NullValue(0)));
+    let Some(Kind::StructValue(nested)) = &spec.fields["nested"].kind else {
+        panic!("not a struct: {spec:?}");
+    };
+    assert_eq!(nested.fields["ok"].kind, Some(Kind::BoolValue(true)));
+    assert_eq!(
+        req.value.clone().unwrap().kind,
+        Some(Kind::StringValue("text".into()))
+    );
+    assert_eq!(req.items.clone().unwrap().values.len(), 2);
+    assert!(req.specs[0].fields.contains_key("from_file"));
+
+    // Responses print the plain JSON.
+    let resp = tonic_clap::output::Response::new(&req).unwrap();
+    assert_eq!(
+        resp.json,
+        serde_json::json!({
+            "spec": {"name": "n1", "nested": {"ok": true}, "none": null},
+            "value": "text",
+            "items": [1.0, "two"],
+            "specs": [{"from_file": [1.0, 2.0]}],
+        })
+    );
+
+    let err = cmd
+        .try_get_matches_from(["test", "--spec", "[1]"])
+        .unwrap_err();
+    assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_recursive_message() {
+    use crate::fieldtypes::TreeRequest;
+
+    let cmd = TreeRequest::augment_args(Command::new("test"));
+    let help = cmd.clone().render_help().to_string();
+    assert!(help.contains("--root.name <NAME>"), "{help}");
+    assert!(help.contains("--root.leaf.value <VALUE>"), "{help}");
+    assert!(help.contains("Arg: Vec<TreeNode> (JSON)"), "{help}");
+
+    let matches = cmd
+        .try_get_matches_from([
+            "test",
+            "--root.name",
+            "r",
+            "--root.children",
+            r#"{"name": "c1", "children": [{"name": "c2"}]}"#,
+            "--root.by_name",
+            r#"c3={"leaf": {"subtrees": [{"name": "c4"}]}}"#,
+            "--leaf.subtrees",
+            r#"{"name": "c5"}"#,
+        ])
+        .unwrap();
+    let req = TreeRequest::from_arg_matches(&matches).unwrap();
+    let root = req.root.unwrap();
+    assert_eq!(root.name, "r");
+    assert_eq!(root.children[0].children[0].name, "c2");
+    assert_eq!(
+        root.by_name["c3"].leaf.as_ref().unwrap().subtrees[0].name,
+        "c4"
+    );
+    assert_eq!(req.leaf.unwrap().subtrees[0].name, "c5");
+}
+
+#[test]
+fn test_boxed_field() {
+    use crate::fieldtypes::{ExprRequest, Label};
+    use prost::Message;
+
+    let cmd = ExprRequest::augment_args(Command::new("test"));
+    let help = cmd.clone().render_help().to_string();
+    // Field order is kept, recursion stops at the boxed Expr.
+    let flags = [
+        "--expr.op",
+        "--expr.left <LEFT>",
+        "--expr.right",
+        "--expr.operand.value",
+        "--expr.operand.parent <PARENT>",
+        "--label",
+    ];
+    let positions = flags.map(|f| help.find(f).unwrap_or_else(|| panic!("{f}: {help}")));
+    assert!(positions.is_sorted(), "{help}");
+
+    let matches = cmd
+        .try_get_matches_from([
+            "test",
+            "--expr.op",
+            "+",
+            "--expr.left",
+            r#"{"op": "-", "operand": {"detail": {"@type": "fieldtypes.Label", "key": "k"}}}"#,
+            "--expr.operand.value",
+            "-3",
+        ])
+        .unwrap();
+    let req = ExprRequest::from_arg_matches(&matches).unwrap();
+    let expr = req.expr.unwrap();
+    assert_eq!(expr.op, "+");
+    assert_eq!(expr.operand.unwrap().value, -3);
+    let left = expr.left.unwrap();
+    assert_eq!(left.op, "-");
+    let detail = left.operand.unwrap().detail.unwrap();
+    assert_eq!(Label::decode(detail.value.as_slice()).unwrap().key, "k");
+    assert!(expr.right.is_none());
+}
+
+#[test]
+fn test_boxed_oneof_member() {
+    use crate::fieldtypes::{ExprRequest, expr::Group};
+
+    let cmd = ExprRequest::augment_args(Command::new("test"));
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["test", "--expr.group.Nested", r#"{"op": "-"}"#])
+        .unwrap();
+    let req = ExprRequest::from_arg_matches(&matches).unwrap();
+    let Some(Group::Nested(nested)) = req.expr.unwrap().group else {
+        panic!("not a nested expr");
+    };
+    assert_eq!(nested.op, "-");
+
+    let matches = cmd
+        .try_get_matches_from(["test", "--expr.group.Literal", "x"])
+        .unwrap();
+    let req = ExprRequest::from_arg_matches(&matches).unwrap();
+    assert_eq!(req.expr.unwrap().group, Some(Group::Literal("x".into())));
+}
+
+#[test]
+fn test_field_behavior() {
+    use crate::fieldtypes::BehaviorRequest;
+    use crate::legacy::LegacyRequest;
+    use clap::error::ErrorKind;
+    use tonic_clap::arg::RequestArgs;
+
+    let cmd = BehaviorRequest::augment_args(Command::new("test"));
+    let help = cmd.clone().render_help().to_string();
+    assert!(!help.contains("--id"), "{help}");
+    assert!(help.contains("Arg: String, required"), "{help}");
+    // label is required, but none of its own fields are.
+    assert!(help.contains("--label.key"), "{help}");
+
+    // Required fields are checked once --json-data is merged, so clap does not require them.
+    let requests = |args: &[&str], json_data: Option<&str>| {
+        let matches = cmd.clone().try_get_matches_from(args).unwrap();
+        RequestArgs::<BehaviorRequest>::from_arg_matches(&matches)
+            .unwrap()
+            .into_requests(json_data)
+    };
+    let err = requests(&["test", "--note", "n"], None).unwrap_err();
+    assert_eq!(err.exit_code(), 2);
+    assert_eq!(err.to_string(), "missing required options: --name, --tags");
+
+    let err = cmd
+        .clone()
+        .try_get_matches_from(["test", "--name", "n", "--tags", "t", "--id", "1"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+
+    let req = requests(&["test", "--name", "n", "--tags", "t"], None).unwrap();
+    assert_eq!(req[0].name, "n");
+    assert_eq!(req[0].tags, ["t"]);
+    // A default value given on the command line counts as set.
+    let req = requests(&["test", "--name", "", "--tags", "t"], None).unwrap();
+    assert_eq!(req[0].name, "");
+
+    // --json-data sets the required fields the options leave out.
+    let json_data = r#"{"name": "j", "tags": ["a"]} {"tags": ["b"]}"#;
+    let req = requests(&["test", "--name", "n"], Some(json_data)).unwrap();
+    assert_eq!(req[0].name, "n");
+    assert_eq!(req[1].name, "n");
+    assert_eq!(req[1].tags, ["b"]);
+    let err = requests(&["test", "--note", "n"], Some(json_data)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "missing required options: --name in request 2"
+    );
+
+    // proto2 required.
+    let cmd = LegacyRequest::augment_args(Command::new("test"));
+    let matches = cmd.clone().try_get_matches_from(["test", "--count", "1"]);
+    let err = RequestArgs::<LegacyRequest>::from_arg_matches(&matches.unwrap())
+        .unwrap()
+        .into_requests(None)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "missing required options: --name");
+    // from_arg_matches checks the required fields too.
+    let matches = cmd.clone().try_get_matches_from(["test", "--count", "0"]);
+    let err = LegacyRequest::from_arg_matches(&matches.unwrap()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    let matches = cmd.try_get_matches_from(["test", "--name", "n"]).unwrap();
+    let req = LegacyRequest::from_arg_matches(&matches).unwrap();
+    assert_eq!(req.name, "n");
+    assert_eq!(req.count, None);
+}
+
+#[test]
+fn test_flatten_two_requests() {
+    use crate::fieldtypes::TimeRequest;
+    use crate::legacy::LegacyRequest;
+    use clap::Parser;
+
+    #[derive(clap::Parser, Debug)]
+    struct Flattened {
+        #[command(flatten)]
+        time: TimeRequest,
+        #[command(flatten)]
+        legacy: LegacyRequest,
+    }
+
+    let args =
+        Flattened::try_parse_from(["test", "--timeout", "1s", "--name", "n", "--count", "2"])
+            .unwrap();
+    assert_eq!(args.time.timeout.unwrap().seconds, 1);
+    assert_eq!(args.legacy.name, "n");
+    assert_eq!(args.legacy.count, Some(2));
+}
+
+#[tokio::test]
+async fn test_run_main_without_transport() {
+    use clap::Parser;
+    let args = crate::HWArgs::try_parse_from(["hwgencli"]).unwrap();
+    let err = args.run_main().await.unwrap_err();
+    assert_eq!(err.exit_code(), 2);
+    assert_eq!(err.to_string(), "no transport mode given, see --help");
+}
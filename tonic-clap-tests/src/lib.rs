@@ -1,7 +1,16 @@
 pub mod helloworld {
     include!("../gen/helloworld.rs");
 }
+pub mod fieldtypes {
+    include!("../gen/fieldtypes.rs");
+}
+pub mod legacy {
+    include!("../gen/legacy.rs");
+}
 pub mod google {
+    pub mod api {
+        include!("../gen/google.api.rs");
+    }
     pub mod protobuf {
         include!("../gen/google.protobuf.rs");
     }
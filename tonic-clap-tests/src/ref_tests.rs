@@ -10,7 +10,6 @@ pub mod models {
         Eq,
         Hash,
         ::prost::Message,
-        bevy_reflect::Reflect,
         tonic_clap::TonicClap,
     )]
     pub struct TestWithDerived {
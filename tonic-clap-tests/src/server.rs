@@ -26,8 +26,13 @@ impl greeter_server::Greeter for GreeterImpl {
         &self,
         request: Request<HelloRequest>,
     ) -> Result<Response<HelloReply>, Status> {
+        let name = request.into_inner().name;
+        // Lets the tests fail a call.
+        if name == "missing" {
+            return Err(Status::not_found("no greeting for missing"));
+        }
         let reply = HelloReply {
-            message: format!("Hello {}!", request.into_inner().name),
+            message: format!("Hello {name}!"),
         };
         Ok(Response::new(reply))
     }
@@ -0,0 +1,86 @@
+//! `google.protobuf.Any` support.
+//! `tonic-clap-build` marks every compiled message with `#[tonic_clap(message = "pkg.Msg")]`,
+//! and the `TonicClap` derive registers it here, keyed by the proto full name.
+//! Any values use the proto3 JSON form: `{"@type": "type.googleapis.com/pkg.Msg", ...fields}`.
+
+use std::{collections::HashMap, sync::OnceLock};
+
+use serde_json::Value;
+
+use crate::schema::MessageSchema;
+
+#[doc(hidden)]
+pub use inventory;
+
+/// A message type that can be packed into and unpacked from Any.
+pub struct MessageType {
+    /// Fully qualified proto name, e.g. `helloworld.Field1`.
+    pub full_name: &'static str,
+    schema: fn() -> &'static MessageSchema,
+    encode: fn(Value) -> Result<Vec<u8>, String>,
+    decode: fn(&[u8]) -> Result<Value, String>,
+}
+
+inventory::collect!(MessageType);
+
+impl MessageType {
+    pub const fn new<T>(full_name: &'static str) -> Self
+    where
+        T: prost::Message
+            + Default
+            + serde::Serialize
+            + serde::de::DeserializeOwned
+            + crate::schema::Message,
+    {
+        Self {
+            full_name,
+            schema: <T as crate::schema::Message>::schema,
+            encode: encode_message::<T>,
+            decode: decode_message::<T>,
+        }
+    }
+
+    pub fn schema(&self) -> &'static MessageSchema {
+        (self.schema)()
+    }
+
+    /// Encode the serde JSON of the message to proto bytes.
+    pub fn encode(&self, value: Value) -> Result<Vec<u8>, String> {
+        (self.encode)(value)
+    }
+
+    /// Decode proto bytes to the serde JSON of the message.
+    pub fn decode(&self, bytes: &[u8]) -> Result<Value, String> {
+        (self.decode)(bytes)
+    }
+}
+
+fn encode_message<T>(value: Value) -> Result<Vec<u8>, String>
+where
+    T: prost::Message + serde::de::DeserializeOwned,
+{
+    let msg = crate::json::from_value::<T>(value).map_err(|e| e.to_string())?;
+    Ok(msg.encode_to_vec())
+}
+
+fn decode_message<T>(bytes: &[u8]) -> Result<Value, String>
+where
+    T: prost::Message + Default + serde::Serialize,
+{
+    let msg = T::decode(bytes).map_err(|e| e.to_string())?;
+    serde_json::to_value(msg).map_err(|e| e.to_string())
+}
+
+/// Find the registered message for a type URL.
+/// Only the part after the last '/' is used, as in the proto Any spec.
+pub fn find(type_url: &str) -> Option<&'static MessageType> {
+    static REGISTRY: OnceLock<HashMap<&'static str, &'static MessageType>> = OnceLock::new();
+    let registry = REGISTRY.get_or_init(|| {
+        inventory::iter::<MessageType>
+            .into_iter()
+            .map(|t| (t.full_name, t))
+            .collect()
+    });
+    let full_name = type_url.rsplit('/').next().unwrap_or(type_url);
+    registry.get(full_name).copied()
+}
@@ -8,15 +8,63 @@ pub mod openssl;
 #[cfg(feature = "autocomplete")]
 pub mod autocomplete;
 
+mod request;
+pub use request::RequestArgs;
+
 #[derive(Args, Debug)]
 pub struct CommonArgs {
-    /// JSON data to convert to proto payload. Ignored when options are specified.
+    /// JSON data to convert to proto payload, `@<FILE>` to read it from a file, or `-` for stdin.
+    /// Concatenated JSON documents send one request each.
+    /// Options given after the method override the fields they set.
     #[arg(short, long)]
     pub json_data: Option<String>,
 
+    /// File to read the JSON data from, `-` for stdin. Same as `--json-data @<FILE>`.
+    #[arg(long, value_name = "FILE", conflicts_with = "json_data")]
+    pub data_file: Option<String>,
+
     /// Do not send the request. Only prints the args.
     #[arg(long, default_value_t = false)]
     pub dry_run: bool,
+
+    /// Format of the printed responses.
+    #[arg(short, long, value_enum, default_value_t)]
+    pub output: crate::output::OutputFormat,
+
+    /// Print only the values a JSONPath or jq path selects in the responses, e.g. `.containers[].id`.
+    /// Strings print without quotes.
+    #[arg(short, long, value_parser = crate::query::Query::parse)]
+    pub query: Option<crate::query::Query>,
+
+    /// Print the connect latency, then the path and request metadata, response headers and
+    /// trailers, status and latency of each call to stderr.
+    #[arg(short, long, default_value_t = false)]
+    pub verbose: bool,
+}
+
+impl CommonArgs {
+    /// The JSON data, read from its file or stdin if it names one.
+    pub fn read_json_data(&self) -> Result<Option<String>, crate::Error> {
+        let path = match (&self.json_data, &self.data_file) {
+            (Some(data), _) if data == "-" => data.as_str(),
+            (Some(data), _) => match data.strip_prefix('@') {
+                Some(path) => path,
+                None => return Ok(Some(data.clone())),
+            },
+            (None, Some(path)) => path.as_str(),
+            (None, None) => return Ok(None),
+        };
+        let data = if path == "-" {
+            let mut data = String::new();
+            std::io::Read::read_to_string(&mut std::io::stdin(), &mut data)
+                .map_err(|e| crate::Error::Other(format!("cannot read stdin: {e}").into()))?;
+            data
+        } else {
+            std::fs::read_to_string(path)
+                .map_err(|e| crate::Error::Other(format!("cannot read file {path}: {e}").into()))?
+        };
+        Ok(Some(data))
+    }
 }
 
 #[derive(Args, Debug)]
@@ -48,6 +96,15 @@ where
             cmd: self.command,
         })
     }
+    /// Like `make_channel`, but connects before returning.
+    pub async fn connect(self) -> Result<CmdCtx<Sub>, crate::Error> {
+        let ep = tonic::transport::Endpoint::from_shared(self.url)?;
+        Ok(CmdCtx {
+            channel: ep.connect().await.map_err(unavailable)?,
+            common: self.common,
+            cmd: self.command,
+        })
+    }
 }
 
 #[derive(Args, Debug)]
@@ -80,6 +137,15 @@ where
             cmd: self.command,
         })
     }
+    /// Like `make_channel`, but connects before returning.
+    pub async fn connect(self) -> Result<CmdCtx<Sub>, crate::Error> {
+        let ep = tonic::transport::Endpoint::from_shared(self.url)?;
+        Ok(CmdCtx {
+            channel: ep.connect().await.map_err(unavailable)?,
+            common: self.common,
+            cmd: self.command,
+        })
+    }
 }
 
 #[cfg(feature = "openssl")]
@@ -129,6 +195,46 @@ where
         };
         Ok(ctx)
     }
+
+    /// Like `make_channel`, but connects before returning.
+    pub async fn connect(self) -> Result<CmdCtx<Sub>, crate::Error> {
+        let ctx = match self {
+            TransportMode::Tcp(tcp) => tcp.connect().await?,
+            #[cfg(feature = "openssl")]
+            TransportMode::TcpSsl(ssl) => CmdCtx {
+                channel: ssl.ssl.connect().await?,
+                common: ssl.common,
+                cmd: ssl.command,
+            },
+            TransportMode::Uds(uds) => uds.connect().await?,
+        };
+        Ok(ctx)
+    }
+
+    pub fn common(&self) -> &CommonArgs {
+        match self {
+            TransportMode::Tcp(tcp) => &tcp.common,
+            #[cfg(feature = "openssl")]
+            TransportMode::TcpSsl(ssl) => &ssl.common,
+            TransportMode::Uds(uds) => &uds.common,
+        }
+    }
+}
+
+/// A failed connection is UNAVAILABLE, as it is for a call over a lazy channel.
+/// The message keeps the whole source chain, e.g. the refused connection.
+pub(crate) fn unavailable(e: tonic::transport::Error) -> crate::Error {
+    let mut message = e.to_string();
+    let mut source = std::error::Error::source(&e);
+    while let Some(e) = source {
+        // Wrapping errors often repeat their source.
+        let text = e.to_string();
+        if !message.ends_with(&text) {
+            message += &format!(": {text}");
+        }
+        source = e.source();
+    }
+    tonic::Status::unavailable(message).into()
 }
 
 /// Default arguments for tonic.
@@ -160,13 +266,26 @@ pub trait ExecuteCmd {
         self,
         channel: tonic::transport::Channel,
         json_data: Option<String>,
-    ) -> Result<Box<dyn std::fmt::Debug>, tonic::Status>;
+        verbose: bool,
+    ) -> Result<crate::output::Responses, crate::Error>;
 }
 
 impl<Sub> DefaultArgs<Sub>
 where
     Sub: clap::Subcommand + std::fmt::Debug + crate::arg::ExecuteCmd,
 {
+    /// Run `run_main`, printing a failure to stderr.
+    /// Returns the exit code of the failure, see `Error::exit_code`.
+    pub async fn run(self) -> std::process::ExitCode {
+        match self.run_main().await {
+            Ok(()) => std::process::ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("error: {e}");
+                std::process::ExitCode::from(e.exit_code())
+            }
+        }
+    }
+
     // Default main function to run a CLI app built with `tonic-clap`.
     pub async fn run_main(self) -> Result<(), crate::Error> {
         #[cfg(feature = "autocomplete")]
@@ -181,18 +300,46 @@ where
                 return Ok(());
             }
         }
-        let ctx = self.transport.expect("no transport mode").make_channel()?;
+        let Some(transport) = self.transport else {
+            return Err(crate::Error::invalid_input(
+                "no transport mode given, see --help",
+            ));
+        };
+        let common = transport.common();
+        // Connect up front to time it apart from the calls.
+        let ctx = if common.verbose && !common.dry_run {
+            let start = std::time::Instant::now();
+            let ctx = transport.connect().await?;
+            eprintln!("* connected in {:?}", start.elapsed());
+            ctx
+        } else {
+            transport.make_channel()?
+        };
         if ctx.common.dry_run {
             println!("dry run: {:?}", ctx.cmd);
             return Ok(());
         }
 
-        let resp = ctx
+        let json_data = ctx.common.read_json_data()?;
+        let result = ctx
             .cmd
-            .execute(ctx.channel, ctx.common.json_data)
-            .await
-            .expect("request failed");
-        println!("{:?}", resp);
-        Ok(())
+            .execute(ctx.channel, json_data, ctx.common.verbose)
+            .await;
+        let print = |resp: &crate::output::Responses| -> Result<(), crate::Error> {
+            match &ctx.common.query {
+                Some(query) => print!("{}", resp.format_query(query, ctx.common.output)),
+                None => print!("{}", resp.format(ctx.common.output)?),
+            }
+            Ok(())
+        };
+        match result {
+            Ok(resp) => print(&resp),
+            // The responses before the failed call are still printed.
+            Err(crate::Error::Partial { responses, error }) => {
+                print(&responses)?;
+                Err(*error)
+            }
+            Err(e) => Err(e),
+        }
     }
 }
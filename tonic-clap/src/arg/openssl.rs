@@ -43,6 +43,20 @@ impl OpensslArgs {
         ));
         Ok(ch)
     }
+
+    /// Like `make_channel`, but connects before returning.
+    pub async fn connect(&self) -> Result<tonic::transport::Channel, crate::Error> {
+        let endpoint = tonic::transport::Endpoint::from_shared(self.url.clone())?;
+        let ch = endpoint
+            .connect_with_connector(tonic_tls::openssl::TlsConnector::new(
+                &endpoint,
+                self.make_connector()?.build(),
+                self.domain.clone(),
+            ))
+            .await
+            .map_err(super::unavailable)?;
+        Ok(ch)
+    }
 }
 
 #[derive(ValueEnum, Clone, Debug)]
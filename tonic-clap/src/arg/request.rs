@@ -0,0 +1,93 @@
+use clap::{ArgMatches, Args, FromArgMatches};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::schema::Message;
+
+/// Request of a method subcommand.
+/// Keeps the matches of the subcommand to merge its options with `--json-data`.
+pub struct RequestArgs<T> {
+    /// Request with the fields of the given options set.
+    pub message: T,
+    matches: ArgMatches,
+}
+
+impl<T: Message + Serialize + DeserializeOwned> RequestArgs<T> {
+    /// The requests to send, one per JSON document in `json_data`, or the request
+    /// of the options without it. Options given on the command line override
+    /// the fields they set in each document. Nested messages are merged.
+    /// Required fields must be set by the options or by every document.
+    /// Without `json_data`, the fields without options are listed in a warning on stderr.
+    pub fn into_requests(self, json_data: Option<&str>) -> Result<Vec<T>, crate::Error> {
+        if json_data.is_none() {
+            let parsed = crate::visit::TCFieldType::parse_cached(T::schema())?;
+            if !parsed.skipped.is_empty() {
+                eprintln!(
+                    "warning: fields without options are left unset, set them with --json-data:\n  {}",
+                    parsed.skipped.join("\n  ")
+                );
+            }
+        }
+        let requests = match json_data {
+            Some(data) => {
+                let mut requests = crate::json::from_json_documents::<T>(data)?;
+                for request in &mut requests {
+                    crate::impl_update_from_arg_matches(request, &self.matches)
+                        .map_err(crate::Error::invalid_input)?;
+                }
+                requests
+            }
+            None => vec![self.message],
+        };
+        for (i, request) in requests.iter().enumerate() {
+            let value = serde_json::to_value(request)?;
+            let missing = crate::clap::missing_required::<T>(&self.matches, Some(&value))?;
+            if missing.is_empty() {
+                continue;
+            }
+            let mut msg = format!("missing required options: {}", missing.join(", "));
+            if requests.len() > 1 {
+                msg += &format!(" in request {}", i + 1);
+            }
+            return Err(crate::Error::invalid_input(msg));
+        }
+        Ok(requests)
+    }
+}
+
+// Options are added only when clap builds the command, e.g. for the subcommand that was
+// actually invoked. `defer` keeps a single fn, so the request must be the only args
+// of its command, as in the generated method subcommands.
+impl<T: Args + Default> Args for RequestArgs<T> {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        cmd.defer(T::augment_args)
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        cmd.defer(T::augment_args_for_update)
+    }
+}
+
+// Required fields are checked by `into_requests`, as --json-data may set them.
+impl<T: FromArgMatches + Default> FromArgMatches for RequestArgs<T> {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        let mut message = T::default();
+        message.update_from_arg_matches(matches)?;
+        Ok(Self {
+            message,
+            matches: matches.clone(),
+        })
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), clap::Error> {
+        self.message.update_from_arg_matches(matches)?;
+        self.matches = matches.clone();
+        Ok(())
+    }
+}
+
+// Dry runs print the request only.
+impl<T: std::fmt::Debug> std::fmt::Debug for RequestArgs<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.message.fmt(f)
+    }
+}
@@ -0,0 +1,174 @@
+//! Unary calls of the method subcommands.
+//! With `--verbose`, every call prints its method path and request metadata before it is sent,
+//! then its response headers and trailers, status and latency, to stderr.
+//! Stdout keeps only the response bodies.
+
+use std::time::{Duration, Instant};
+
+use tonic::{
+    codegen::http::uri::PathAndQuery,
+    metadata::{KeyAndValueRef, MetadataMap},
+};
+
+use crate::output::Response;
+
+/// Send a unary request to the method at `path`, e.g. `/helloworld.Greeter/SayHello`.
+/// The call reads the response as a stream of one message, to keep its trailers apart from
+/// its headers. `verbose` prints the call to stderr, see `CommonArgs::verbose`.
+pub async fn unary<Req, Resp>(
+    channel: tonic::transport::Channel,
+    path: &'static str,
+    request: Req,
+    verbose: bool,
+) -> Result<Response, crate::Error>
+where
+    Req: prost::Message + Send + Sync + 'static,
+    Resp: prost::Message
+        + Default
+        + Send
+        + Sync
+        + serde::Serialize
+        + std::fmt::Debug
+        + crate::schema::Message
+        + 'static,
+{
+    let mut grpc = tonic::client::Grpc::new(channel);
+    // Same as the tonic generated clients.
+    grpc.ready()
+        .await
+        .map_err(|e| tonic::Status::unknown(format!("Service was not ready: {e}")))?;
+    let codec = tonic_prost::ProstCodec::<Req, Resp>::default();
+    let request = tonic::Request::new(request);
+    if verbose {
+        eprint!("{}", describe_request(path, request.metadata()));
+    }
+    let start = Instant::now();
+    let mut headers = None;
+    let result = async {
+        let response = grpc
+            .server_streaming(request, PathAndQuery::from_static(path), codec)
+            .await?;
+        let (metadata, mut body, _) = response.into_parts();
+        headers = Some(metadata);
+        let message = body
+            .message()
+            .await?
+            .ok_or_else(|| tonic::Status::internal("Missing response message."))?;
+        let trailers = body.trailers().await?.unwrap_or_default();
+        Ok::<_, tonic::Status>((message, trailers))
+    }
+    .await;
+    if verbose {
+        let result = result.as_ref().map(|(_, trailers)| trailers);
+        eprint!(
+            "{}",
+            describe_response(headers.as_ref(), result, start.elapsed())
+        );
+    }
+    Response::new(&result?.0)
+}
+
+/// What `--verbose` prints of a request before sending it.
+/// A request timeout shows as its `grpc-timeout` metadata.
+fn describe_request(path: &str, metadata: &MetadataMap) -> String {
+    format!("> {path}\n{}", entries(metadata, "> "))
+}
+
+/// What `--verbose` prints of a response.
+/// `headers` is `None` if the call failed before any, e.g. a trailers-only response.
+fn describe_response(
+    headers: Option<&MetadataMap>,
+    trailers: Result<&MetadataMap, &tonic::Status>,
+    latency: Duration,
+) -> String {
+    let mut out = String::new();
+    if let Some(headers) = headers {
+        out += "* headers\n";
+        out += &entries(headers, "< ");
+    }
+    match trailers {
+        Ok(trailers) => {
+            out += "* trailers\n";
+            out += &entries(trailers, "< ");
+            out += &format!("* status OK in {latency:?}\n");
+        }
+        Err(status) => {
+            out += match headers {
+                Some(_) => "* trailers\n",
+                None => "* headers and trailers\n",
+            };
+            out += &entries(status.metadata(), "< ");
+            out += &format!(
+                "* status {:?}: {} in {latency:?}\n",
+                status.code(),
+                status.message()
+            );
+        }
+    }
+    out
+}
+
+/// One `key: value` line per entry. Binary values print base64 encoded, as sent.
+fn entries(metadata: &MetadataMap, prefix: &str) -> String {
+    let mut out = String::new();
+    for entry in metadata.iter() {
+        let (key, value) = match entry {
+            KeyAndValueRef::Ascii(key, value) => (
+                key.as_str(),
+                String::from_utf8_lossy(value.as_encoded_bytes()),
+            ),
+            KeyAndValueRef::Binary(key, value) => (
+                key.as_str(),
+                String::from_utf8_lossy(value.as_encoded_bytes()),
+            ),
+        };
+        out += &format!("{prefix}{key}: {value}\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        let mut request = tonic::Request::new(());
+        request
+            .metadata_mut()
+            .insert("x-request-id", "r1".parse().unwrap());
+        request.set_timeout(Duration::from_secs(2));
+        assert_eq!(
+            describe_request("/pkg.Svc/Get", request.metadata()),
+            "> /pkg.Svc/Get\n> x-request-id: r1\n> grpc-timeout: 2000000u\n"
+        );
+
+        let mut headers = MetadataMap::new();
+        headers.insert("content-type", "application/grpc".parse().unwrap());
+        headers.insert_bin(
+            "x-trace-bin",
+            tonic::metadata::MetadataValue::from_bytes(b"\x01\x02"),
+        );
+        let mut trailers = MetadataMap::new();
+        trailers.insert("grpc-status", "0".parse().unwrap());
+        trailers.insert("x-cost", "7".parse().unwrap());
+        let latency = Duration::from_millis(3);
+        assert_eq!(
+            describe_response(Some(&headers), Ok(&trailers), latency),
+            "* headers\n< content-type: application/grpc\n\
+             < x-trace-bin: AQI\n* trailers\n< grpc-status: 0\n< x-cost: 7\n\
+             * status OK in 3ms\n"
+        );
+
+        let status = tonic::Status::not_found("no such thing");
+        assert_eq!(
+            describe_response(None, Err(&status), latency),
+            "* headers and trailers\n* status NotFound: no such thing in 3ms\n"
+        );
+        assert_eq!(
+            describe_response(Some(&headers), Err(&status), latency),
+            "* headers\n< content-type: application/grpc\n\
+             < x-trace-bin: AQI\n* trailers\n* status NotFound: no such thing in 3ms\n"
+        );
+    }
+}
@@ -1,193 +1,190 @@
-use bevy_reflect::{TypeInfo, Typed};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase};
 use serde_json::Value;
 
-pub fn impl_from_arg_matches<T>(matches: &clap::ArgMatches) -> Result<T, clap::Error>
-where
-    T: serde::de::DeserializeOwned + Typed,
-{
-    let type_info = T::type_info();
-    let tree = crate::visit::TCFieldType::parse(type_info);
+use crate::{
+    flags::{Flags, Leaf},
+    schema::{DEFAULT_FLAG_SEPARATOR, Message, MessageSchema},
+    value_parser::BytesArg,
+};
+
+/// The request of the options given on the command line.
+/// Fails if the options of required fields are missing.
+pub fn impl_from_arg_matches<T: Message>(matches: &clap::ArgMatches) -> Result<T, clap::Error> {
+    let mut msg = T::default();
+    impl_update_from_arg_matches(&mut msg, matches)?;
+    let missing = missing_required::<T>(matches, None)
+        .map_err(|e| clap::Error::raw(clap::error::ErrorKind::ValueValidation, e))?;
+    if !missing.is_empty() {
+        return Err(clap::Error::raw(
+            clap::error::ErrorKind::MissingRequiredArgument,
+            format!("missing required options: {}\n", missing.join(", ")),
+        ));
+    }
+    Ok(msg)
+}
 
-    let mut root_json = serde_json::Map::new();
+/// Sets the fields of `msg` whose options are given on the command line.
+pub fn impl_update_from_arg_matches<T: Message>(
+    msg: &mut T,
+    matches: &clap::ArgMatches,
+) -> Result<(), clap::Error> {
+    let schema = T::schema();
+    let parsed = crate::visit::TCFieldType::parse_cached(schema)
+        .map_err(|e| clap::Error::raw(clap::error::ErrorKind::ValueValidation, e))?;
+    let tree = &parsed.tree;
+    let separator = flag_separator(schema);
 
+    let mut leaves = HashMap::new();
     tree.visit_nested(&mut |ctx| {
-        // Use the prefix, field_name, and field_type to extract values from matches
         assert!(ctx.field_type.is_primitive());
         let arg_name = if ctx.prefix.is_empty() {
             ctx.field_name.clone()
         } else {
             ctx.prefix.join(".") + "." + ctx.field_name
         };
-
-        // Check if argument exists first
-        if !matches.contains_id(&arg_name) {
-            return;
-        }
-
-        // Extract the primitive value
-        if let Some(field_value) =
-            extract_primitive_value(matches, &arg_name, ctx.field_type.as_primitive())
-        {
-            // Set the value at the correct nested path
-            let mut path_parts = ctx.prefix.to_vec();
-            path_parts.push(ctx.field_name.to_string());
-            set_nested_value(&mut root_json, &path_parts, field_value);
-        }
+        let leaf = Leaf {
+            field_type: ctx.field_type.as_primitive().clone(),
+            long: FlagNames::new(ctx.prefix, ctx.field_name, &separator).long,
+        };
+        leaves.insert(arg_name, leaf);
     });
+    let given = given_ids(matches);
 
-    // Convert the map to JSON Value
-    let json_value = Value::Object(root_json);
-
-    tracing::debug!("Constructed JSON from args: {json_value}");
-    // Use serde to deserialize directly into the target struct
-    match serde_json::from_value::<T>(json_value) {
-        Ok(instance) => Ok(instance),
-        Err(e) => Err(map_serde_error_to_clap(e)),
-    }
-}
-
-fn map_serde_error_to_clap(e: serde_json::Error) -> clap::Error {
-    // If conflicting one of fields args are passed return ArgumentConflict error.
-    if e.is_data() && e.to_string().contains("expected map with a single key") {
-        return clap::Error::raw(
-            clap::error::ErrorKind::ArgumentConflict,
-            "Argument conflict for OneOf fields.",
-        );
-    }
-    clap::Error::raw(
-        clap::error::ErrorKind::ValueValidation,
-        format!("Failed to deserialize struct from arguments: {}", e),
-    )
+    msg.merge_flags(&Flags::new(matches, schema, &leaves, &given))
 }
 
-// Helper function to extract primitive value from matches
-fn extract_primitive_value(
+// Helper function to extract primitive value from matches.
+// Files of bytes flags are read here.
+pub(crate) fn extract_primitive_value(
     matches: &clap::ArgMatches,
     arg_name: &str,
     field_type: &crate::visit::TCFieldTypePrimitive,
-) -> Option<Value> {
+) -> Result<Option<Value>, String> {
     use crate::visit::TCFieldTypePrimitive;
 
-    match field_type {
-        TCFieldTypePrimitive::String => {
-            if let Ok(Some(value)) = matches.try_get_one::<String>(arg_name) {
-                Some(Value::String(value.clone()))
-            } else {
-                None
-            }
-        }
-        TCFieldTypePrimitive::I32 => {
-            if let Ok(Some(value)) = matches.try_get_one::<i32>(arg_name) {
-                Some(Value::Number(serde_json::Number::from(*value)))
-            } else {
-                None
-            }
-        }
-        TCFieldTypePrimitive::I64 => {
-            if let Ok(Some(value)) = matches.try_get_one::<i64>(arg_name) {
-                Some(Value::Number(serde_json::Number::from(*value)))
-            } else {
-                None
-            }
-        }
-        TCFieldTypePrimitive::F32 => {
-            if let Ok(Some(value)) = matches.try_get_one::<f32>(arg_name) {
-                serde_json::Number::from_f64(*value as f64).map(Value::Number)
-            } else {
-                None
-            }
-        }
-        TCFieldTypePrimitive::F64 => {
-            if let Ok(Some(value)) = matches.try_get_one::<f64>(arg_name) {
-                serde_json::Number::from_f64(*value).map(Value::Number)
-            } else {
-                None
-            }
-        }
-        TCFieldTypePrimitive::U32 => {
-            if let Ok(Some(value)) = matches.try_get_one::<u32>(arg_name) {
-                Some(Value::Number(serde_json::Number::from(*value)))
-            } else {
-                None
-            }
-        }
-        TCFieldTypePrimitive::U64 => {
-            if let Ok(Some(value)) = matches.try_get_one::<u64>(arg_name) {
-                Some(Value::Number(serde_json::Number::from(*value)))
-            } else {
-                None
-            }
-        }
-        TCFieldTypePrimitive::U8 => {
-            if let Ok(Some(value)) = matches.try_get_one::<u8>(arg_name) {
-                Some(Value::Number(serde_json::Number::from(*value)))
-            } else {
-                None
-            }
-        }
-        TCFieldTypePrimitive::Bool => {
-            if let Ok(Some(value)) = matches.try_get_one::<bool>(arg_name) {
-                Some(Value::Bool(*value))
-            } else if matches.get_flag(arg_name) {
-                Some(Value::Bool(true))
+    let value = match field_type {
+        TCFieldTypePrimitive::Map(_, value_type) => {
+            if let Ok(Some(entries)) = matches.try_get_many::<(String, Value)>(arg_name) {
+                let mut entries = entries.cloned().collect::<serde_json::Map<_, _>>();
+                // bytes values of files are kept as `@path`, see `parse_json_value`.
+                let bytes_values: Vec<&mut Value> = match value_type.as_primitive() {
+                    TCFieldTypePrimitive::Bytes => entries.values_mut().collect(),
+                    TCFieldTypePrimitive::Wrapper(inner)
+                        if matches!(**inner, TCFieldTypePrimitive::Bytes) =>
+                    {
+                        entries
+                            .values_mut()
+                            .filter_map(|v| v.get_mut("value"))
+                            .collect()
+                    }
+                    _ => Vec::new(),
+                };
+                for value in bytes_values {
+                    if let Some(path) = value.as_str().and_then(|s| s.strip_prefix('@')) {
+                        *value = BytesArg::File(path.into()).read()?.into();
+                    }
+                }
+                Some(Value::Object(entries))
             } else {
                 None
             }
         }
         TCFieldTypePrimitive::Vec(inner) => {
-            if !inner.is_primitive() || (inner.is_primitive() && inner.as_primitive().is_vec()) {
-                // skip nested vec.
+            if !inner.is_primitive() || inner.as_primitive().is_collection() {
+                // skip nested collections.
                 None
             } else {
-                // Handle vectors - for now assume Vec<String>
-                if let Ok(Some(values)) = matches.try_get_many::<String>(arg_name) {
-                    let vec_values: Vec<String> = values.cloned().collect();
-                    let json_array: Vec<Value> =
-                        vec_values.into_iter().map(Value::String).collect();
-                    Some(Value::Array(json_array))
-                } else {
-                    None
-                }
+                extract_values(matches, arg_name, inner.as_primitive())?.map(Value::Array)
             }
         }
-    }
+        _ => extract_values(matches, arg_name, field_type)?.and_then(|v| v.into_iter().next()),
+    };
+    Ok(value)
 }
 
-// Helper function to set a value at a nested path in JSON
-fn set_nested_value(root: &mut serde_json::Map<String, Value>, path: &[String], value: Value) {
-    if path.is_empty() {
-        // This shouldn't happen, but handle gracefully
-        return;
-    }
+// Read all typed values clap parsed for the arg and convert them to JSON.
+// The rust type must match the value parser of the primitive.
+fn extract_values(
+    matches: &clap::ArgMatches,
+    arg_name: &str,
+    field_type: &crate::visit::TCFieldTypePrimitive,
+) -> Result<Option<Vec<Value>>, String> {
+    use crate::visit::TCFieldTypePrimitive;
 
-    if path.len() == 1 {
-        // Base case: set the final value
-        root.insert(path[0].clone(), value);
-    } else {
-        // Recursive case: navigate deeper
-        let key = &path[0];
-        let remaining_path = &path[1..];
-
-        // Get or create the nested object
-        let entry = root
-            .entry(key.clone())
-            .or_insert_with(|| Value::Object(serde_json::Map::new()));
-
-        // Ensure it's an object and recurse
-        if let Value::Object(nested_map) = entry {
-            set_nested_value(nested_map, remaining_path, value);
+    fn many<T>(
+        matches: &clap::ArgMatches,
+        arg_name: &str,
+        to_json: impl Fn(&T) -> Value,
+    ) -> Option<Vec<Value>>
+    where
+        T: std::any::Any + Clone + Send + Sync + 'static,
+    {
+        if let Ok(Some(values)) = matches.try_get_many::<T>(arg_name) {
+            Some(values.map(to_json).collect())
         } else {
-            // Replace non-object with object and recurse
-            *entry = Value::Object(serde_json::Map::new());
-            if let Value::Object(nested_map) = entry {
-                set_nested_value(nested_map, remaining_path, value);
-            }
+            None
         }
     }
+
+    let values = match field_type {
+        TCFieldTypePrimitive::String => many::<String>(matches, arg_name, |v| v.clone().into()),
+        // proto enums are parsed into their i32 number.
+        TCFieldTypePrimitive::I32 | TCFieldTypePrimitive::Enum(_) => {
+            many::<i32>(matches, arg_name, |v| (*v).into())
+        }
+        TCFieldTypePrimitive::I64 => many::<i64>(matches, arg_name, |v| (*v).into()),
+        TCFieldTypePrimitive::F32 => many::<f32>(matches, arg_name, |v| (*v).into()),
+        TCFieldTypePrimitive::F64 => many::<f64>(matches, arg_name, |v| (*v).into()),
+        TCFieldTypePrimitive::U32 => many::<u32>(matches, arg_name, |v| (*v).into()),
+        TCFieldTypePrimitive::U64 => many::<u64>(matches, arg_name, |v| (*v).into()),
+        TCFieldTypePrimitive::Bool => many::<bool>(matches, arg_name, |v| (*v).into()),
+        // serde uses a number array for Vec<u8>
+        TCFieldTypePrimitive::Bytes => match matches.try_get_many::<BytesArg>(arg_name) {
+            Ok(Some(values)) => Some(
+                values
+                    .map(|v| v.read().map(Value::from))
+                    .collect::<Result<_, _>>()?,
+            ),
+            _ => None,
+        },
+        // well known types are parsed into their serde JSON.
+        TCFieldTypePrimitive::Json(_)
+        | TCFieldTypePrimitive::Any
+        | TCFieldTypePrimitive::Struct(_)
+        | TCFieldTypePrimitive::Timestamp
+        | TCFieldTypePrimitive::Duration => many::<Value>(matches, arg_name, |v| v.clone()),
+        // wrapper serde JSON is the scalar in a `value` field.
+        TCFieldTypePrimitive::Wrapper(inner) => {
+            extract_values(matches, arg_name, inner)?.map(|values| {
+                values
+                    .into_iter()
+                    .map(|v| serde_json::json!({ "value": v }))
+                    .collect()
+            })
+        }
+        TCFieldTypePrimitive::Vec(_) | TCFieldTypePrimitive::Map(..) => None,
+    };
+    Ok(values)
 }
 
-pub fn impl_augment_args(mut cmd: clap::Command, type_info: &TypeInfo) -> clap::Command {
-    let tree = crate::visit::TCFieldType::parse(type_info);
+/// Adds one option per request field to `cmd`.
+/// Fails if the request type cannot be mapped to options at all.
+pub fn impl_augment_args(
+    mut cmd: clap::Command,
+    schema: &'static MessageSchema,
+) -> Result<clap::Command, crate::Error> {
+    let parsed = crate::visit::TCFieldType::parse_cached(schema)?;
+    let tree = &parsed.tree;
+    let separator = flag_separator(schema);
+    // Skipped fields can still be set with --json-data.
+    if !parsed.skipped.is_empty() {
+        cmd = cmd.after_help(format!(
+            "warning: fields without options, set them with --json-data:\n  {}",
+            parsed.skipped.join("\n  ")
+        ));
+    }
     let mut args = Vec::new();
     tree.visit_nested(&mut |ctx| {
         assert!(ctx.field_type.is_primitive());
@@ -196,25 +193,159 @@ pub fn impl_augment_args(mut cmd: clap::Command, type_info: &TypeInfo) -> clap::
         } else {
             ctx.prefix.join(".") + "." + ctx.field_name
         };
+        let names = FlagNames::new(ctx.prefix, ctx.field_name, &separator);
         let primitive_type = ctx.field_type.as_primitive();
         if primitive_type.is_vec() && !primitive_type.is_primitive_vec() {
-            // We only support primitive vec for now.
+            // Nested vec is not supported.
             return;
         }
-        let help_text = format!("Arg: {}", primitive_type.display_primitive_type());
+        let mut type_text = format!("Arg: {}", primitive_type.display_primitive_type());
+        if let Some(hint) = primitive_type.format_hint() {
+            type_text += &format!(" ({hint})");
+        }
+        // Checked by `missing_required` once --json-data is merged, not by clap.
+        if ctx.required {
+            type_text += ", required";
+        }
 
         let (value_parser, action) = primitive_type.get_clap_value_parse();
-        let arg = clap::Arg::new(&arg_name)
-            .long(&arg_name)
-            .value_name(ctx.field_name.to_uppercase())
-            .help(&help_text)
-            .required(false) // TODO: support required properly. Currently many protos does not indicate if field is required.
+        let mut arg = clap::Arg::new(&arg_name)
+            .long(&names.long)
+            .aliases(names.aliases)
+            .value_name(ctx.field_name.to_shouty_snake_case())
             .action(action)
-            .value_parser(value_parser.clone());
-        args.push(arg);
+            .value_parser(value_parser.clone())
+            .value_delimiter(primitive_type.value_delimiter())
+            .allow_negative_numbers(primitive_type.is_signed_number());
+        // The proto comment, with the type still shown.
+        arg = match ctx.doc {
+            Some(doc) => arg
+                .help(format!("{} [{type_text}]", summary(doc)))
+                .long_help(format!("{doc}\n\n{type_text}")),
+            None => arg.help(type_text),
+        };
+        if let Some(innermost) = ctx.oneofs.last() {
+            let oneof = innermost.oneof.split('.').map(|s| s.to_kebab_case());
+            let oneof = oneof.collect::<Vec<_>>().join(&separator);
+            arg = arg.help_heading(format!("Oneof {oneof}"));
+        }
+        args.push((arg, ctx.oneofs.to_vec()));
+    });
+
+    // Each oneof is a group. Flags of different variants conflict with each other.
+    let mut groups = BTreeMap::<&str, Vec<(&str, clap::Id)>>::new();
+    for (arg, oneofs) in &args {
+        for v in oneofs {
+            groups
+                .entry(&v.oneof)
+                .or_default()
+                .push((&v.variant, arg.get_id().clone()));
+        }
+    }
+    for (arg, oneofs) in &args {
+        let conflicts = oneofs.iter().flat_map(|v| {
+            groups[v.oneof.as_str()]
+                .iter()
+                .filter(|(variant, _)| *variant != v.variant)
+                .map(|(_, id)| id.clone())
+        });
+        cmd = cmd.arg(arg.clone().conflicts_with_all(conflicts));
+    }
+    for (oneof, members) in groups {
+        let group = clap::ArgGroup::new(oneof.to_string())
+            .args(members.into_iter().map(|(_, id)| id))
+            .multiple(true);
+        cmd = cmd.group(group);
+    }
+    Ok(cmd)
+}
+
+/// First paragraph of a proto comment, on one line.
+fn summary(doc: &str) -> String {
+    let paragraph = doc.split("\n\n").next().unwrap_or_default();
+    paragraph.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Ids of the args given on the command line.
+fn given_ids(matches: &clap::ArgMatches) -> HashSet<String> {
+    matches
+        .ids()
+        .filter(|id| {
+            matches.value_source(id.as_str()) == Some(clap::parser::ValueSource::CommandLine)
+        })
+        .map(|id| id.to_string())
+        .collect()
+}
+
+/// Options of the required fields that are neither given in `matches`
+/// nor set in `request`, the serde JSON of the request the options were merged into.
+/// As in proto3, a field at its default value is unset, unless its option is given.
+pub(crate) fn missing_required<T: Message>(
+    matches: &clap::ArgMatches,
+    request: Option<&Value>,
+) -> Result<Vec<String>, crate::Error> {
+    let schema = T::schema();
+    let parsed = crate::visit::TCFieldType::parse_cached(schema)?;
+    let separator = flag_separator(schema);
+    let given = given_ids(matches);
+    let mut missing = Vec::new();
+    parsed.tree.visit_nested(&mut |ctx| {
+        if !ctx.required {
+            return;
+        }
+        let mut path = ctx.prefix.iter().chain([ctx.field_name]);
+        let id = path.clone().cloned().collect::<Vec<_>>().join(".");
+        let field = request.and_then(|value| path.try_fold(value, |v, name| v.get(name)));
+        if !given.contains(&id) && field.is_none_or(is_default) {
+            let names = FlagNames::new(ctx.prefix, ctx.field_name, &separator);
+            missing.push(format!("--{}", names.long));
+        }
     });
-    for arg in args {
-        cmd = cmd.arg(arg);
+    Ok(missing)
+}
+
+fn is_default(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Bool(b) => !b,
+        Value::Number(n) => n.as_f64() == Some(0.0),
+        Value::String(s) => s.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        Value::Object(fields) => fields.is_empty(),
+    }
+}
+
+fn flag_separator(schema: &MessageSchema) -> String {
+    schema
+        .flag_separator
+        .unwrap_or(DEFAULT_FLAG_SEPARATOR)
+        .to_string()
+}
+
+/// Option names of a field path. Arg ids stay the rust field path joined by ".".
+struct FlagNames {
+    /// Kebab-case, e.g. `--one-of-field.one-of1.one-of-str`.
+    long: String,
+    /// Hidden aliases: the arg id, snake_case and proto JSON camelCase names.
+    aliases: Vec<String>,
+}
+
+impl FlagNames {
+    fn new(prefix: &[String], field_name: &str, separator: &str) -> Self {
+        let path = prefix.iter().map(String::as_str).chain([field_name]);
+        let join =
+            |case: fn(&str) -> String| path.clone().map(case).collect::<Vec<_>>().join(separator);
+        let long = join(|s| s.to_kebab_case());
+        let mut aliases = Vec::new();
+        for alias in [
+            path.clone().collect::<Vec<_>>().join("."),
+            join(|s| s.to_snake_case()),
+            join(|s| s.to_lower_camel_case()),
+        ] {
+            if alias != long && !aliases.contains(&alias) {
+                aliases.push(alias);
+            }
+        }
+        Self { long, aliases }
     }
-    cmd
 }
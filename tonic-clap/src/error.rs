@@ -0,0 +1,127 @@
+use std::fmt;
+
+/// Errors of tonic-clap cli apps.
+#[derive(Debug)]
+pub enum Error {
+    /// A request type, or a field in it, has no cli representation.
+    UnsupportedType {
+        /// Name of the offending type in its schema, e.g. `Foo` for the message `pkg.Foo`.
+        type_path: String,
+        /// Field path from the request to the type, joined by ".". Empty for the request itself.
+        prefix: String,
+        reason: String,
+    },
+    /// Request data given on the command line is not valid for the request.
+    InvalidInput(String),
+    Json(serde_json::Error),
+    Transport(tonic::transport::Error),
+    Status(tonic::Status),
+    /// A call failed after the earlier requests of the command got their responses.
+    Partial {
+        responses: crate::output::Responses,
+        error: Box<Error>,
+    },
+    Other(Box<dyn std::error::Error + Send + Sync + 'static>),
+}
+
+impl Error {
+    pub(crate) fn unsupported(
+        type_path: &str,
+        prefix: &[String],
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::UnsupportedType {
+            type_path: type_path.to_string(),
+            prefix: prefix.join("."),
+            reason: reason.into(),
+        }
+    }
+
+    pub(crate) fn invalid_input(e: impl fmt::Display) -> Self {
+        Self::InvalidInput(e.to_string())
+    }
+}
+
+impl Error {
+    /// Process exit code for the error.
+    /// A failed call exits with 64 plus its gRPC code, e.g. 69 for NOT_FOUND and 78 for UNAVAILABLE.
+    /// Invalid request data exits with 2, like other usage errors. Other errors exit with 1.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Error::Status(status) => crate::status::exit_code(status.code()),
+            Error::InvalidInput(_) => 2,
+            Error::Partial { error, .. } => error.exit_code(),
+            _ => 1,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnsupportedType {
+                type_path,
+                prefix,
+                reason,
+            } => {
+                write!(f, "unsupported type {type_path}")?;
+                if !prefix.is_empty() {
+                    write!(f, " at {prefix}")?;
+                }
+                write!(f, ": {reason}")
+            }
+            Error::InvalidInput(msg) => f.write_str(msg),
+            Error::Json(e) => write!(f, "invalid json: {e}"),
+            Error::Transport(e) => write!(f, "transport error: {e}"),
+            Error::Status(status) => write!(f, "request failed: {}", crate::status::report(status)),
+            Error::Partial { responses, error } => {
+                write!(f, "{error} (after {} successful calls)", responses.0.len())
+            }
+            Error::Other(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::UnsupportedType { .. } | Error::InvalidInput(_) => None,
+            Error::Json(e) => Some(e),
+            Error::Transport(e) => Some(e),
+            Error::Status(status) => Some(status),
+            Error::Partial { error, .. } => Some(error.as_ref()),
+            Error::Other(e) => Some(e.as_ref()),
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl From<tonic::transport::Error> for Error {
+    fn from(e: tonic::transport::Error) -> Self {
+        Error::Transport(e)
+    }
+}
+
+impl From<tonic::Status> for Error {
+    fn from(status: tonic::Status) -> Self {
+        Error::Status(status)
+    }
+}
+
+impl From<Box<dyn std::error::Error + Send + Sync + 'static>> for Error {
+    fn from(e: Box<dyn std::error::Error + Send + Sync + 'static>) -> Self {
+        Error::Other(e)
+    }
+}
+
+#[cfg(feature = "openssl")]
+impl From<openssl::error::ErrorStack> for Error {
+    fn from(e: openssl::error::ErrorStack) -> Self {
+        Error::Other(e.into())
+    }
+}
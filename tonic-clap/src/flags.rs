@@ -0,0 +1,231 @@
+//! Typed flag values for the `merge_flags` methods generated by the `TonicClap` derive.
+//! Fields are only set when one of their flags is given on the command line.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    schema::{Field, Kind, Message, MessageSchema},
+    value_parser::BytesArg,
+    visit::TCFieldTypePrimitive,
+};
+
+/// Flags of one message or oneof of the request.
+pub struct Flags<'a> {
+    matches: &'a clap::ArgMatches,
+    schema: &'static MessageSchema,
+    /// Flags by arg id.
+    leaves: &'a HashMap<String, Leaf>,
+    /// Ids of the flags given on the command line.
+    given: &'a HashSet<String>,
+    /// Path of this message joined by ".", with a trailing "." unless it is the request.
+    prefix: String,
+}
+
+impl<'a> Flags<'a> {
+    pub(crate) fn new(
+        matches: &'a clap::ArgMatches,
+        schema: &'static MessageSchema,
+        leaves: &'a HashMap<String, Leaf>,
+        given: &'a HashSet<String>,
+    ) -> Self {
+        Self {
+            matches,
+            schema,
+            leaves,
+            given,
+            prefix: String::new(),
+        }
+    }
+
+    fn id(&self, name: &str) -> String {
+        format!("{}{name}", self.prefix)
+    }
+
+    fn field(&self, name: &str) -> Option<&'static Field> {
+        self.schema.fields.iter().find(|f| f.name == name)
+    }
+
+    /// Flags of a message or oneof field.
+    pub fn nested(&self, name: &str) -> Result<Flags<'a>, clap::Error> {
+        let schema = match self.field(name).map(|f| f.kind) {
+            Some(Kind::Message(schema) | Kind::Oneof(schema)) => schema(),
+            _ => {
+                return Err(clap::Error::raw(
+                    clap::error::ErrorKind::ValueValidation,
+                    format!("{name} is not a message field of {}", self.schema.name),
+                ));
+            }
+        };
+        Ok(Flags {
+            matches: self.matches,
+            schema,
+            leaves: self.leaves,
+            given: self.given,
+            prefix: self.id(name) + ".",
+        })
+    }
+
+    /// Whether the flag of the field, or any flag nested in it, is given.
+    pub fn contains(&self, name: &str) -> bool {
+        let id = self.id(name);
+        self.given.iter().any(|given| {
+            given
+                .strip_prefix(id.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+        })
+    }
+
+    pub fn scalar<T: FlagValue>(&self, field: &mut T, name: &str) -> Result<(), clap::Error> {
+        if let Some(value) = self.get_many(name)?.and_then(|mut v| v.pop()) {
+            *field = value;
+        }
+        Ok(())
+    }
+
+    pub fn optional<T: FlagValue>(
+        &self,
+        field: &mut Option<T>,
+        name: &str,
+    ) -> Result<(), clap::Error> {
+        if let Some(value) = self.get_many(name)?.and_then(|mut v| v.pop()) {
+            *field = Some(value);
+        }
+        Ok(())
+    }
+
+    pub fn repeated<T: FlagValue>(
+        &self,
+        field: &mut Vec<T>,
+        name: &str,
+    ) -> Result<(), clap::Error> {
+        if let Some(values) = self.get_many(name)? {
+            *field = values;
+        }
+        Ok(())
+    }
+
+    /// A message field is either expanded into flags,
+    /// or, if nested too deep or recursive, a single JSON flag.
+    pub fn message<M: Message + DeserializeOwned>(
+        &self,
+        field: &mut Option<M>,
+        name: &str,
+    ) -> Result<(), clap::Error> {
+        if self.leaves.contains_key(&self.id(name)) {
+            return self.json(field, name);
+        }
+        if self.contains(name) {
+            let mut msg = field.take().unwrap_or_default();
+            msg.merge_flags(&self.nested(name)?)?;
+            *field = Some(msg);
+        }
+        Ok(())
+    }
+
+    /// Message field that is not an `Option`, e.g. a oneof variant.
+    pub fn required_message<M: Message + DeserializeOwned>(
+        &self,
+        field: &mut M,
+        name: &str,
+    ) -> Result<(), clap::Error> {
+        let mut value = Some(std::mem::take(field));
+        self.message(&mut value, name)?;
+        *field = value.unwrap_or_default();
+        Ok(())
+    }
+
+    /// Field whose flag value is built as JSON, e.g. maps and repeated messages.
+    pub fn json<T: DeserializeOwned>(&self, field: &mut T, name: &str) -> Result<(), clap::Error> {
+        let id = self.id(name);
+        let Some(leaf) = self.leaves.get(&id).filter(|_| self.given.contains(&id)) else {
+            return Ok(());
+        };
+        let long = leaf.long.as_str();
+        let value = crate::clap::extract_primitive_value(self.matches, &id, &leaf.field_type)
+            .map_err(|e| error(long, e))?;
+        let Some(mut value) = value else {
+            return Ok(());
+        };
+        // Messages are given as proto3 JSON, other values are parsed into serde JSON.
+        if let Some(field) = self.field(name)
+            && leaf.field_type.takes_message_json()
+        {
+            value = crate::json::field_from_proto_json(field, value).map_err(|e| error(long, e))?;
+        }
+        tracing::debug!("Constructed JSON from --{long}: {value}");
+        *field = crate::json::from_value(value).map_err(|e| error(long, e))?;
+        Ok(())
+    }
+
+    fn get_many<T: FlagValue>(&self, name: &str) -> Result<Option<Vec<T>>, clap::Error> {
+        let id = self.id(name);
+        if !self.given.contains(&id) {
+            return Ok(None);
+        }
+        T::get_many(self.matches, &id).map_err(|e| {
+            let long = self
+                .leaves
+                .get(&id)
+                .map_or(id.as_str(), |l| l.long.as_str());
+            error(long, e)
+        })
+    }
+}
+
+/// A flag of the request.
+pub(crate) struct Leaf {
+    pub field_type: TCFieldTypePrimitive,
+    /// Long option name, for error messages.
+    pub long: String,
+}
+
+fn error(long: &str, e: impl std::fmt::Display) -> clap::Error {
+    clap::Error::raw(
+        clap::error::ErrorKind::ValueValidation,
+        format!("--{long}: {e}"),
+    )
+}
+
+/// Rust types of scalar fields, read from the values their flag parser yields.
+pub trait FlagValue: Sized {
+    #[doc(hidden)]
+    fn get_many(matches: &clap::ArgMatches, id: &str) -> Result<Option<Vec<Self>>, String>;
+}
+
+macro_rules! flag_value {
+    ($($t:ty),*) => {
+        $(
+            impl FlagValue for $t {
+                fn get_many(
+                    matches: &clap::ArgMatches,
+                    id: &str,
+                ) -> Result<Option<Vec<Self>>, String> {
+                    let values = matches.try_get_many::<$t>(id).map_err(|e| e.to_string())?;
+                    Ok(values.map(|v| v.cloned().collect()))
+                }
+            }
+        )*
+    };
+}
+
+// proto enums are parsed into their i32 number.
+flag_value!(String, bool, i32, i64, u32, u64, f32, f64);
+
+// Files of bytes flags are read here.
+impl FlagValue for Vec<u8> {
+    fn get_many(matches: &clap::ArgMatches, id: &str) -> Result<Option<Vec<Self>>, String> {
+        let values = matches
+            .try_get_many::<BytesArg>(id)
+            .map_err(|e| e.to_string())?;
+        values.map(|v| v.map(BytesArg::read).collect()).transpose()
+    }
+}
+
+impl FlagValue for prost::bytes::Bytes {
+    fn get_many(matches: &clap::ArgMatches, id: &str) -> Result<Option<Vec<Self>>, String> {
+        let values = Vec::<u8>::get_many(matches, id)?;
+        Ok(values.map(|v| v.into_iter().map(Self::from).collect()))
+    }
+}
@@ -0,0 +1,848 @@
+//! proto3 JSON mapping, see <https://protobuf.dev/programming-guides/json/>.
+//! Messages derive serde on the prost structs, i.e. rust field names, enums as numbers,
+//! int64 and bytes as numbers and oneofs as `{"Variant": value}`. Requests and responses
+//! are converted between that serde JSON and proto3 JSON by the message schema.
+
+use base64::Engine;
+use heck::{ToLowerCamelCase, ToSnakeCase};
+use serde::de::{
+    IntoDeserializer, Visitor,
+    value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer},
+};
+use serde_json::{Map, Value};
+
+use crate::{
+    schema::{EnumSchema, Field, Kind, Label, MessageSchema, Scalar},
+    wkt::{self, StructType},
+};
+
+/// Convert the proto3 JSON of a message to the serde JSON of its prost struct.
+/// Proto field names are taken as well as JSON names, and enums and 64 bit integers
+/// as numbers as well as strings.
+pub fn from_proto_json(schema: &MessageSchema, value: Value) -> Result<Value, String> {
+    message_from(schema, value).map_err(|e| e.to_string())
+}
+
+/// Convert the serde JSON of a prost struct to the proto3 JSON of the message.
+/// Unset fields, empty lists and empty maps are left out. Scalars with default values are kept.
+pub fn to_proto_json(schema: &MessageSchema, value: Value) -> Value {
+    message_to(schema, value)
+}
+
+/// Same as [`from_proto_json`] for the value of one field.
+pub(crate) fn field_from_proto_json(field: &Field, value: Value) -> Result<Value, String> {
+    field_from(field, value).map_err(|e| e.to_string())
+}
+
+/// Deserialize a request from proto3 JSON.
+pub fn from_json_str<T>(data: &str) -> Result<T, crate::Error>
+where
+    T: serde::de::DeserializeOwned + crate::schema::Message,
+{
+    let value = serde_json::from_str::<Value>(data)
+        .map_err(|e| crate::Error::invalid_input(format!("invalid json: {e}")))?;
+    let value = from_proto_json(T::schema(), value).map_err(crate::Error::invalid_input)?;
+    from_value(value).map_err(crate::Error::invalid_input)
+}
+
+/// Deserialize one request per proto3 JSON document in `data`.
+/// Documents may be concatenated, e.g. one per line.
+/// Errors report the line and column in `data`, of the document if it is not valid for the request.
+pub fn from_json_documents<T>(data: &str) -> Result<Vec<T>, crate::Error>
+where
+    T: serde::de::DeserializeOwned + crate::schema::Message,
+{
+    let mut requests = Vec::new();
+    let mut documents = serde_json::Deserializer::from_str(data).into_iter::<Value>();
+    loop {
+        let rest = &data[documents.byte_offset()..];
+        let start = data.len() - rest.trim_start().len();
+        let Some(value) = documents.next() else {
+            break;
+        };
+        let invalid = |e: &dyn std::fmt::Display| {
+            let (line, column) = position(data, start);
+            crate::Error::invalid_input(format!(
+                "invalid request at line {line} column {column}: {e}"
+            ))
+        };
+        let value = value.map_err(|e| crate::Error::invalid_input(format!("invalid json: {e}")))?;
+        let value = from_proto_json(T::schema(), value).map_err(|e| invalid(&e))?;
+        requests.push(from_value(value).map_err(|e| invalid(&e))?);
+    }
+    if requests.is_empty() {
+        return Err(crate::Error::invalid_input(
+            "no JSON document in the request data",
+        ));
+    }
+    Ok(requests)
+}
+
+/// 1-based line and column of a byte offset, as serde_json reports them.
+fn position(data: &str, offset: usize) -> (usize, usize) {
+    let before = &data[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Same as `serde_json::from_value`, but also takes the strings "NaN", "Infinity" and
+/// "-Infinity" for floats, which serde JSON has no numbers for.
+pub(crate) fn from_value<T: serde::de::DeserializeOwned>(value: Value) -> serde_json::Result<T> {
+    T::deserialize(Floats(value))
+}
+
+/// Deserializer of serde JSON that reads the non-finite float strings as floats.
+/// Nested values are wrapped too, other values deserialize as from a `Value`.
+struct Floats(Value);
+
+impl Floats {
+    fn non_finite(&self) -> Option<f64> {
+        match self.0.as_str()? {
+            "NaN" => Some(f64::NAN),
+            "Infinity" => Some(f64::INFINITY),
+            "-Infinity" => Some(f64::NEG_INFINITY),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for Floats {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> serde::Deserializer<'de> for Floats {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Array(items) => {
+                let mut seq = SeqDeserializer::new(items.into_iter().map(Floats));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Value::Object(map) => {
+                let mut map =
+                    MapDeserializer::new(map.into_iter().map(|(k, v)| (MapKey(k), Floats(v))));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        self.deserialize_f64(visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.non_finite() {
+            Some(f) => visitor.visit_f64(f),
+            None => self.0.deserialize_f64(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    // Oneofs are `{"Variant": value}`.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Object(map) if map.len() == 1 => {
+                let map =
+                    MapDeserializer::new(map.into_iter().map(|(k, v)| (MapKey(k), Floats(v))));
+                visitor.visit_enum(MapAccessDeserializer::new(map))
+            }
+            value => value.deserialize_enum(name, variants, visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Object key, deserialized as `serde_json` does: map keys of
+/// numbers and bools are parsed from the string.
+struct MapKey(String);
+
+impl MapKey {
+    fn parsed(self) -> Value {
+        match serde_json::from_str(&self.0) {
+            Ok(value @ (Value::Number(_) | Value::Bool(_))) => value,
+            _ => Value::String(self.0),
+        }
+    }
+}
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for MapKey {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident)*) => {$(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+            self.parsed().$method(visitor)
+        }
+    )*};
+}
+
+impl<'de> serde::Deserializer<'de> for MapKey {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        visitor.visit_string(self.0)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64
+    }
+
+    serde::forward_to_deserialize_any! {
+        char str string bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Conversion error with the JSON path it occurred at.
+struct Error {
+    /// Innermost segment first.
+    path: Vec<String>,
+    message: String,
+}
+
+impl Error {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            path: Vec::new(),
+            message: message.into(),
+        }
+    }
+
+    fn at(mut self, segment: impl Into<String>) -> Self {
+        self.path.push(segment.into());
+        self
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut path = String::new();
+        for segment in self.path.iter().rev() {
+            if !path.is_empty() && !segment.starts_with('[') {
+                path.push('.');
+            }
+            path.push_str(segment);
+        }
+        match path.is_empty() {
+            true => write!(f, "{}", self.message),
+            false => write!(f, "{path}: {}", self.message),
+        }
+    }
+}
+
+/// Messages with a special proto3 JSON form.
+enum WellKnown {
+    Timestamp,
+    Duration,
+    Any,
+    FieldMask,
+    Struct(StructType),
+    /// Wrappers are the JSON of the wrapped scalar.
+    Wrapper,
+}
+
+impl WellKnown {
+    fn of(schema: &MessageSchema) -> Option<Self> {
+        let name = schema.full_name?.strip_prefix("google.protobuf.")?;
+        let well_known = match name {
+            "Timestamp" => Self::Timestamp,
+            "Duration" => Self::Duration,
+            "Any" => Self::Any,
+            "FieldMask" => Self::FieldMask,
+            "DoubleValue" | "FloatValue" | "Int64Value" | "UInt64Value" | "Int32Value"
+            | "UInt32Value" | "BoolValue" | "StringValue" | "BytesValue" => Self::Wrapper,
+            _ => Self::Struct(StructType::from_name(name)?),
+        };
+        Some(well_known)
+    }
+}
+
+fn message_from(schema: &MessageSchema, value: Value) -> Result<Value, Error> {
+    match (WellKnown::of(schema), value) {
+        (Some(WellKnown::Timestamp), Value::String(s)) => {
+            wkt::parse_timestamp(&s).map_err(Error::new)
+        }
+        (Some(WellKnown::Duration), Value::String(s)) => {
+            wkt::parse_proto_duration(&s).map_err(Error::new)
+        }
+        (Some(WellKnown::FieldMask), Value::String(s)) => {
+            let paths = s
+                .split(',')
+                .filter(|p| !p.is_empty())
+                .map(|p| {
+                    p.split('.')
+                        .map(|s| s.to_snake_case())
+                        .collect::<Vec<_>>()
+                        .join(".")
+                })
+                .collect::<Vec<_>>();
+            Ok(serde_json::json!({ "paths": paths }))
+        }
+        (Some(WellKnown::Struct(struct_type)), value) => {
+            struct_type.convert_json(value).map_err(Error::new)
+        }
+        (Some(WellKnown::Wrapper), value) if !value.is_object() => {
+            let field = value_field(schema)?;
+            Ok(serde_json::json!({ "value": field_from(field, value)? }))
+        }
+        (Some(WellKnown::Any), Value::Object(obj)) if obj.contains_key("@type") => any_from(obj),
+        // other well known types are the same as their serde JSON.
+        (_, Value::Object(obj)) => fields_from(schema, obj),
+        (_, value) => Err(Error::new(format!(
+            "expected an object for {}, got {value}",
+            schema.full_name.unwrap_or(schema.name)
+        ))),
+    }
+}
+
+fn fields_from(schema: &MessageSchema, obj: Map<String, Value>) -> Result<Value, Error> {
+    let mut converted = Map::new();
+    for (key, value) in obj {
+        let Some((field, oneof)) = find_field(schema, &key) else {
+            return Err(Error::new(format!("unknown field '{key}'")));
+        };
+        let (name, value) = match (oneof, field.kind) {
+            // A oneof member, set on the oneof field as `{"Variant": value}`.
+            (Some(oneof), _) => {
+                if value.is_null() {
+                    continue;
+                }
+                if converted.contains_key(oneof.name) {
+                    return Err(Error::new(format!(
+                        "more than one member of oneof '{}' is set",
+                        oneof.proto_name()
+                    )));
+                }
+                let value = field_from(field, value).map_err(|e| e.at(&key))?;
+                (oneof.name, serde_json::json!({ field.name: value }))
+            }
+            // The serde JSON of a oneof field.
+            (None, Kind::Oneof(oneof)) => {
+                let value = oneof_from(oneof(), value).map_err(|e| e.at(&key))?;
+                (field.name, value)
+            }
+            (None, _) => (
+                field.name,
+                field_from(field, value).map_err(|e| e.at(&key))?,
+            ),
+        };
+        // null is the default value.
+        if !value.is_null() {
+            converted.insert(name.to_string(), value);
+        }
+    }
+    Ok(Value::Object(converted))
+}
+
+/// The field of a JSON key, and the oneof field of the message if it is a oneof member.
+fn find_field(
+    schema: &MessageSchema,
+    key: &str,
+) -> Option<(&'static Field, Option<&'static Field>)> {
+    let matches = |field: &Field| field.json_name() == key || field.proto_name() == key;
+    schema.fields.iter().find_map(|field| match field.kind {
+        Kind::Oneof(_) if field.name == key => Some((field, None)),
+        Kind::Oneof(oneof) => oneof()
+            .fields
+            .iter()
+            .find(|member| matches(member))
+            .map(|member| (member, Some(field))),
+        _ => matches(field).then_some((field, None)),
+    })
+}
+
+fn oneof_from(schema: &MessageSchema, value: Value) -> Result<Value, Error> {
+    let Value::Object(obj) = value else {
+        return Ok(value);
+    };
+    let mut converted = Map::new();
+    for (variant, value) in obj {
+        let Some(field) = schema.fields.iter().find(|f| f.name == variant) else {
+            return Err(Error::new(format!("unknown oneof member '{variant}'")));
+        };
+        let value = field_from(field, value).map_err(|e| e.at(&variant))?;
+        converted.insert(variant, value);
+    }
+    Ok(Value::Object(converted))
+}
+
+fn field_from(field: &Field, value: Value) -> Result<Value, Error> {
+    if value.is_null() && !is_struct_value(field.kind) {
+        return Ok(Value::Null);
+    }
+    match (field.label, value) {
+        (Label::Repeated, Value::Array(items)) => items
+            .into_iter()
+            .enumerate()
+            .map(|(i, v)| kind_from(field.kind, v).map_err(|e| e.at(format!("[{i}]"))))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        (Label::Map(_), Value::Object(entries)) => entries
+            .into_iter()
+            .map(|(k, v)| {
+                let v = kind_from(field.kind, v).map_err(|e| e.at(format!("[{k}]")))?;
+                Ok((k, v))
+            })
+            .collect::<Result<_, _>>()
+            .map(Value::Object),
+        (Label::Repeated, value) => Err(Error::new(format!("expected an array, got {value}"))),
+        (Label::Map(_), value) => Err(Error::new(format!("expected an object, got {value}"))),
+        (Label::Singular | Label::Optional, value) => kind_from(field.kind, value),
+    }
+}
+
+/// google.protobuf.Value, whose JSON null is a value.
+fn is_struct_value(kind: Kind) -> bool {
+    matches!(kind, Kind::Message(schema) if schema().full_name == Some("google.protobuf.Value"))
+}
+
+fn kind_from(kind: Kind, value: Value) -> Result<Value, Error> {
+    match kind {
+        Kind::Scalar(scalar) => scalar_from(scalar, value),
+        Kind::Enum(schema) => enum_from(schema(), value),
+        Kind::Message(schema) | Kind::Oneof(schema) => message_from(schema(), value),
+    }
+}
+
+fn scalar_from(scalar: Scalar, value: Value) -> Result<Value, Error> {
+    let invalid = |value: &Value| Error::new(format!("invalid {scalar:?} value {value}"));
+    match (scalar, value) {
+        (Scalar::String, value @ Value::String(_)) | (Scalar::Bool, value @ Value::Bool(_)) => {
+            Ok(value)
+        }
+        (Scalar::I32 | Scalar::I64, value) => {
+            let n = integer(&value).ok_or_else(|| invalid(&value))?;
+            let n = i64::try_from(n).map_err(|_| invalid(&value))?;
+            if scalar == Scalar::I32 && i32::try_from(n).is_err() {
+                return Err(invalid(&value));
+            }
+            Ok(n.into())
+        }
+        (Scalar::U32 | Scalar::U64, value) => {
+            let n = integer(&value).ok_or_else(|| invalid(&value))?;
+            let n = u64::try_from(n).map_err(|_| invalid(&value))?;
+            if scalar == Scalar::U32 && u32::try_from(n).is_err() {
+                return Err(invalid(&value));
+            }
+            Ok(n.into())
+        }
+        // serde JSON has no NaN or infinity, they are kept as strings for `from_value`.
+        (Scalar::F32 | Scalar::F64, Value::String(s))
+            if matches!(s.as_str(), "NaN" | "Infinity" | "-Infinity") =>
+        {
+            Ok(Value::String(s))
+        }
+        (Scalar::F32 | Scalar::F64, Value::String(s)) => s
+            .parse::<f64>()
+            .ok()
+            .filter(|f| f.is_finite())
+            .map(Value::from)
+            .ok_or_else(|| invalid(&Value::String(s))),
+        (Scalar::F32 | Scalar::F64, value @ Value::Number(_)) => Ok(value),
+        (Scalar::Bytes, Value::String(s)) => {
+            let engines = [
+                base64::engine::general_purpose::STANDARD,
+                base64::engine::general_purpose::URL_SAFE,
+                base64::engine::general_purpose::STANDARD_NO_PAD,
+                base64::engine::general_purpose::URL_SAFE_NO_PAD,
+            ];
+            engines
+                .iter()
+                .find_map(|engine| engine.decode(&s).ok())
+                .map(Value::from)
+                .ok_or_else(|| Error::new(format!("invalid base64 '{s}'")))
+        }
+        // serde uses a number array for bytes.
+        (Scalar::Bytes, value @ Value::Array(_)) => Ok(value),
+        (_, value) => Err(invalid(&value)),
+    }
+}
+
+/// Integers are JSON numbers or decimal strings.
+fn integer(value: &Value) -> Option<i128> {
+    match value {
+        Value::Number(n) => n
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| n.as_u64().map(i128::from))
+            .or_else(|| n.as_f64().filter(|f| f.fract() == 0.0).map(|f| f as i128)),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn enum_from(schema: &EnumSchema, value: Value) -> Result<Value, Error> {
+    match &value {
+        Value::String(name) => schema
+            .values
+            .iter()
+            .find(|(n, _)| *n == name.as_str())
+            .map(|(_, v)| Value::from(*v))
+            .ok_or_else(|| Error::new(format!("unknown {} value '{name}'", schema.name))),
+        _ => scalar_from(Scalar::I32, value),
+    }
+}
+
+/// `{"@type": url, ...fields}`, or `{"@type": url, "value": json}` for well known types.
+fn any_from(mut obj: Map<String, Value>) -> Result<Value, Error> {
+    let type_url = match obj.remove("@type") {
+        Some(Value::String(type_url)) => type_url,
+        Some(type_url) => {
+            return Err(Error::new(format!(
+                "@type must be a string, got {type_url}"
+            )));
+        }
+        None => unreachable!("checked by the caller"),
+    };
+    let message = crate::any::find(&type_url)
+        .ok_or_else(|| Error::new(format!("unknown Any type '{type_url}'")))?;
+    let schema = message.schema();
+    let value = match obj.remove("value") {
+        Some(value) if obj.is_empty() && WellKnown::of(schema).is_some() => value,
+        Some(value) => {
+            obj.insert("value".to_string(), value);
+            Value::Object(obj)
+        }
+        None => Value::Object(obj),
+    };
+    let value = message_from(schema, value).map_err(|e| e.at(format!("({type_url})")))?;
+    let bytes = message
+        .encode(value)
+        .map_err(|e| Error::new(format!("invalid {}: {e}", message.full_name)))?;
+    Ok(serde_json::json!({ "type_url": type_url, "value": bytes }))
+}
+
+/// The `value` field of a wrapper.
+fn value_field(schema: &MessageSchema) -> Result<&'static Field, Error> {
+    schema
+        .fields
+        .iter()
+        .find(|f| f.name == "value")
+        .ok_or_else(|| Error::new(format!("{} has no value field", schema.name)))
+}
+
+fn message_to(schema: &MessageSchema, value: Value) -> Value {
+    let well_known = WellKnown::of(schema);
+    let Value::Object(mut obj) = value else {
+        return value;
+    };
+    let seconds_nanos = |obj: &Map<String, Value>| {
+        let seconds = obj.get("seconds").and_then(Value::as_i64).unwrap_or(0);
+        let nanos = obj.get("nanos").and_then(Value::as_i64).unwrap_or(0);
+        (seconds, nanos as i32)
+    };
+    match well_known {
+        Some(WellKnown::Timestamp) => {
+            let (seconds, nanos) = seconds_nanos(&obj);
+            return wkt::format_timestamp(seconds, nanos).into();
+        }
+        Some(WellKnown::Duration) => {
+            let (seconds, nanos) = seconds_nanos(&obj);
+            return wkt::format_duration(seconds, nanos).into();
+        }
+        Some(WellKnown::FieldMask) => {
+            let paths = obj.get("paths").and_then(Value::as_array);
+            let paths = paths.into_iter().flatten().filter_map(Value::as_str);
+            let paths = paths.map(|p| {
+                let segments = p.split('.').map(|s| s.to_lower_camel_case());
+                segments.collect::<Vec<_>>().join(".")
+            });
+            return paths.collect::<Vec<_>>().join(",").into();
+        }
+        Some(WellKnown::Struct(_)) => {
+            let value = Value::Object(obj);
+            return wkt::struct_to_json(&value).unwrap_or(value);
+        }
+        Some(WellKnown::Wrapper) => {
+            if let (Ok(field), Some(value)) = (value_field(schema), obj.remove("value")) {
+                return field_to(field, value);
+            }
+        }
+        Some(WellKnown::Any) => {
+            if let Some(unpacked) = any_to(&obj) {
+                return unpacked;
+            }
+        }
+        None => {}
+    }
+
+    let mut converted = Map::new();
+    for field in schema.fields {
+        let Some(value) = obj.remove(field.name).filter(|v| !v.is_null()) else {
+            continue;
+        };
+        // Empty lists and maps are unset.
+        let empty = match &value {
+            Value::Array(items) => items.is_empty(),
+            Value::Object(entries) => entries.is_empty(),
+            _ => false,
+        };
+        if empty && matches!(field.label, Label::Repeated | Label::Map(_)) {
+            continue;
+        }
+        match (field.kind, value) {
+            // `{"Variant": value}` is the member field.
+            (Kind::Oneof(oneof), Value::Object(variants)) => {
+                for (variant, value) in variants {
+                    if let Some(member) = oneof().fields.iter().find(|f| f.name == variant) {
+                        converted.insert(member.json_name().into_owned(), field_to(member, value));
+                    }
+                }
+            }
+            (_, value) => {
+                converted.insert(field.json_name().into_owned(), field_to(field, value));
+            }
+        }
+    }
+    Value::Object(converted)
+}
+
+fn field_to(field: &Field, value: Value) -> Value {
+    match (field.label, value) {
+        (Label::Repeated, Value::Array(items)) => items
+            .into_iter()
+            .map(|v| kind_to(field.kind, v))
+            .collect::<Vec<_>>()
+            .into(),
+        (Label::Map(_), Value::Object(entries)) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(k, v)| (k, kind_to(field.kind, v)))
+                .collect(),
+        ),
+        (_, value) => kind_to(field.kind, value),
+    }
+}
+
+fn kind_to(kind: Kind, value: Value) -> Value {
+    match (kind, value) {
+        // 64 bit integers are strings, as JSON numbers lose precision.
+        (Kind::Scalar(Scalar::I64 | Scalar::U64), Value::Number(n)) => n.to_string().into(),
+        (Kind::Scalar(Scalar::Bytes), Value::Array(items)) => {
+            match serde_json::from_value::<Vec<u8>>(Value::Array(items.clone())) {
+                Ok(bytes) => base64::engine::general_purpose::STANDARD
+                    .encode(bytes)
+                    .into(),
+                Err(_) => Value::Array(items),
+            }
+        }
+        // Unknown numbers stay numbers.
+        (Kind::Enum(schema), Value::Number(n)) => schema()
+            .values
+            .iter()
+            .find(|(_, v)| n.as_i64() == Some(i64::from(*v)))
+            .map_or(Value::Number(n), |(name, _)| Value::from(*name)),
+        (Kind::Message(schema) | Kind::Oneof(schema), value) => message_to(schema(), value),
+        (_, value) => value,
+    }
+}
+
+/// The registered message of an Any, with an `@type` key.
+/// Unknown types are left to the generic `typeUrl` and `value` fields.
+fn any_to(obj: &Map<String, Value>) -> Option<Value> {
+    let type_url = obj.get("type_url")?.as_str()?;
+    let bytes = serde_json::from_value::<Vec<u8>>(obj.get("value")?.clone()).ok()?;
+    let message = crate::any::find(type_url)?;
+    let schema = message.schema();
+    let value = message_to(schema, message.decode(&bytes).ok()?);
+    let mut unpacked = Map::new();
+    unpacked.insert("@type".to_string(), type_url.into());
+    match (WellKnown::of(schema), value) {
+        (None, Value::Object(fields)) => unpacked.extend(fields),
+        (_, value) => {
+            unpacked.insert("value".to_string(), value);
+        }
+    }
+    Some(Value::Object(unpacked))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, crate::TonicClap)]
+    #[serde(default)]
+    struct Msg {
+        count: i64,
+        data: Vec<u8>,
+        #[tonic_clap(enumeration = "Color")]
+        color: i32,
+        nested: Option<Box<Msg>>,
+        #[tonic_clap(proto_name = "x_1", json_name = "customName")]
+        renamed: String,
+        choice: Option<Choice>,
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, crate::TonicClap)]
+    enum Choice {
+        #[tonic_clap(proto_name = "text_1")]
+        Text1(String),
+        Number(u64),
+    }
+
+    #[derive(Clone, Copy, crate::TonicClap)]
+    #[allow(dead_code)]
+    enum Color {
+        #[tonic_clap(name = "COLOR_RED")]
+        Red = 0,
+        #[tonic_clap(name = "COLOR_BLUE")]
+        Blue = 1,
+    }
+
+    fn from(json: Value) -> Result<Msg, String> {
+        let value = from_proto_json(<Msg as crate::schema::Message>::schema(), json)?;
+        serde_json::from_value(value).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_from_proto_json() {
+        let msg = from(serde_json::json!({
+            "count": "-5",
+            "data": "aGk=",
+            "color": "COLOR_BLUE",
+            "nested": {"count": 3, "text1": "t"},
+            "customName": "c",
+        }))
+        .unwrap();
+        assert_eq!(
+            (msg.count, msg.data.as_slice(), msg.color),
+            (-5, &b"hi"[..], 1)
+        );
+        assert_eq!(msg.renamed, "c");
+        let nested = msg.nested.unwrap();
+        assert_eq!(
+            (nested.count, nested.choice),
+            (3, Some(Choice::Text1("t".into())))
+        );
+
+        // proto names, and the serde JSON of oneofs.
+        let msg = from(serde_json::json!({"x_1": "p", "text_1": "t", "color": 1})).unwrap();
+        assert_eq!((msg.renamed.as_str(), msg.color), ("p", 1));
+        assert_eq!(msg.choice, Some(Choice::Text1("t".into())));
+        let msg = from(serde_json::json!({"choice": {"Number": "7"}, "count": null})).unwrap();
+        assert_eq!(msg.choice, Some(Choice::Number(7)));
+
+        for (json, error) in [
+            (serde_json::json!({"unknown": 1}), "unknown field 'unknown'"),
+            (
+                serde_json::json!({"color": "GREEN"}),
+                "color: unknown Color value 'GREEN'",
+            ),
+            (
+                serde_json::json!({"nested": {"count": "x"}}),
+                "nested.count: invalid I64 value",
+            ),
+            (
+                serde_json::json!({"text1": "a", "number": 1}),
+                "more than one member of oneof",
+            ),
+            (serde_json::json!({"data": "%%"}), "data: invalid base64"),
+        ] {
+            let e = from(json).unwrap_err();
+            assert!(e.contains(error), "{e}");
+        }
+    }
+
+    #[test]
+    fn test_non_finite_floats() {
+        #[derive(Debug, Default, serde::Deserialize, crate::TonicClap)]
+        #[serde(default)]
+        struct Floats {
+            double: f64,
+            float: f32,
+            values: Vec<f64>,
+            optional: Option<f64>,
+        }
+
+        let value = serde_json::json!({
+            "double": "NaN",
+            "float": "-Infinity",
+            "values": ["Infinity", 1.5, "2"],
+            "optional": "NaN",
+        });
+        let value = from_proto_json(<Floats as crate::schema::Message>::schema(), value).unwrap();
+        let msg = from_value::<Floats>(value).unwrap();
+        assert!(msg.double.is_nan());
+        assert_eq!(msg.float, f32::NEG_INFINITY);
+        assert_eq!(msg.values, [f64::INFINITY, 1.5, 2.0]);
+        assert!(msg.optional.unwrap().is_nan());
+
+        let value = serde_json::json!({"double": "inf"});
+        let e = from_proto_json(<Floats as crate::schema::Message>::schema(), value).unwrap_err();
+        assert!(e.contains("double: invalid F64 value"), "{e}");
+    }
+
+    #[test]
+    fn test_to_proto_json() {
+        let msg = Msg {
+            count: 1 << 60,
+            data: b"hi".to_vec(),
+            color: 1,
+            nested: Some(Box::new(Msg {
+                color: 7,
+                choice: Some(Choice::Number(2)),
+                ..Default::default()
+            })),
+            renamed: "r".into(),
+            choice: None,
+        };
+        let json = to_proto_json(
+            <Msg as crate::schema::Message>::schema(),
+            serde_json::to_value(&msg).unwrap(),
+        );
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "count": "1152921504606846976",
+                "data": "aGk=",
+                "color": "COLOR_BLUE",
+                "nested": {"count": "0", "data": "", "color": 7, "customName": "", "number": "2"},
+                "customName": "r",
+            })
+        );
+        assert_eq!(from(json).unwrap(), msg);
+    }
+}
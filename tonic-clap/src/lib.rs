@@ -1,11 +1,34 @@
+// Lets the `TonicClap` derive be used within this crate.
+extern crate self as tonic_clap;
+
 mod clap;
-pub use clap::{impl_augment_args, impl_from_arg_matches};
+pub use clap::{impl_augment_args, impl_from_arg_matches, impl_update_from_arg_matches};
 
 pub use tonic_clap_macros::TonicClap;
 
 pub mod visit;
 
+pub mod schema;
+
+pub mod flags;
+
+pub mod any;
+
+pub mod json;
+
+pub mod output;
+
+pub mod query;
+
+pub mod call;
+
+pub mod status;
+
+mod value_parser;
+
+mod wkt;
+
 pub mod arg;
 
-/// Common boxed error.
-pub type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
+mod error;
+pub use error::Error;
@@ -0,0 +1,245 @@
+//! Printing of method responses.
+//! Responses are kept as proto3 JSON, which every format but `debug` is rendered from.
+
+use serde_json::Value;
+
+/// Format of the printed responses.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// proto3 JSON, one response per line.
+    Json,
+    /// Indented proto3 JSON.
+    #[default]
+    JsonPretty,
+    /// YAML, one document per response.
+    Yaml,
+    /// Aligned columns, one row per response or per element of its only repeated message field.
+    Table,
+    /// Rust Debug form of the response messages.
+    Debug,
+}
+
+/// Response of a method call.
+#[derive(Debug)]
+pub struct Response {
+    /// proto3 JSON of the message, with Any values unpacked.
+    pub json: Value,
+    /// Debug form of the message.
+    pub debug: String,
+}
+
+impl Response {
+    /// Fails if the message does not serialize to JSON, e.g. a map with non-string keys.
+    pub fn new<T>(msg: &T) -> Result<Self, crate::Error>
+    where
+        T: serde::Serialize + std::fmt::Debug + crate::schema::Message,
+    {
+        let value = serde_json::to_value(msg)?;
+        Ok(Self {
+            json: crate::json::to_proto_json(T::schema(), value),
+            debug: format!("{msg:?}"),
+        })
+    }
+}
+
+impl serde::Serialize for Response {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.json.serialize(serializer)
+    }
+}
+
+/// Responses of a command, one per request sent.
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(transparent)]
+pub struct Responses(pub Vec<Response>);
+
+impl From<Response> for Responses {
+    fn from(response: Response) -> Self {
+        Self(vec![response])
+    }
+}
+
+impl Responses {
+    /// Render the responses in the format, ending with a newline unless there is nothing to print.
+    pub fn format(&self, format: OutputFormat) -> Result<String, crate::Error> {
+        let mut out = String::new();
+        match format {
+            OutputFormat::Json => {
+                for response in &self.0 {
+                    out += &format!("{}\n", response.json);
+                }
+            }
+            OutputFormat::JsonPretty => {
+                for response in &self.0 {
+                    out += &format!("{:#}\n", response.json);
+                }
+            }
+            OutputFormat::Yaml => {
+                for (i, response) in self.0.iter().enumerate() {
+                    if i > 0 {
+                        out += "---\n";
+                    }
+                    out += &serde_yaml_ng::to_string(&response.json)
+                        .map_err(|e| crate::Error::Other(e.into()))?;
+                }
+            }
+            OutputFormat::Table => out = table(&self.0),
+            OutputFormat::Debug => {
+                for response in &self.0 {
+                    out += &format!("{}\n", response.debug);
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Render the values the query selects in the responses, one per line.
+    /// Strings print raw, and other scalars as JSON.
+    /// Messages and lists print as JSON, indented unless the format is `json`.
+    pub fn format_query(&self, query: &crate::query::Query, format: OutputFormat) -> String {
+        let mut out = String::new();
+        for value in self.0.iter().flat_map(|r| query.select(&r.json)) {
+            match value {
+                Value::String(s) => out += s,
+                Value::Array(_) | Value::Object(_) if format != OutputFormat::Json => {
+                    out += &format!("{value:#}")
+                }
+                value => out += &value.to_string(),
+            }
+            out += "\n";
+        }
+        out
+    }
+}
+
+/// Rows are the responses, or the elements of a response's only field if it is a list of messages.
+/// Columns are the fields of the rows in the order they first appear.
+fn table(responses: &[Response]) -> String {
+    let mut rows = Vec::new();
+    for response in responses {
+        match &response.json {
+            Value::Object(obj) => match obj.values().next() {
+                Some(Value::Array(items))
+                    if obj.len() == 1 && items.iter().all(Value::is_object) =>
+                {
+                    rows.extend(items.iter().filter_map(Value::as_object))
+                }
+                _ => rows.push(obj),
+            },
+            _ => continue,
+        }
+    }
+    let mut columns: Vec<&str> = Vec::new();
+    for row in &rows {
+        for key in row.keys() {
+            if !columns.contains(&key.as_str()) {
+                columns.push(key);
+            }
+        }
+    }
+    if columns.is_empty() {
+        return String::new();
+    }
+
+    let header = columns
+        .iter()
+        .map(|c| heck::AsShoutySnakeCase(c).to_string());
+    let mut cells = vec![header.collect::<Vec<_>>()];
+    for row in rows {
+        cells.push(columns.iter().map(|c| cell(row.get(*c))).collect());
+    }
+    let widths: Vec<usize> = (0..columns.len())
+        .map(|i| {
+            cells
+                .iter()
+                .map(|r| r[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut out = String::new();
+    for row in cells {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        out += line.trim_end();
+        out += "\n";
+    }
+    out
+}
+
+/// Strings print without quotes, messages and lists as compact JSON.
+fn cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn responses(values: &[Value]) -> Responses {
+        Responses(
+            values
+                .iter()
+                .map(|v| Response {
+                    json: v.clone(),
+                    debug: format!("Msg({v})"),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_format() {
+        let resp = responses(&[
+            serde_json::json!({"message": "hi", "count": "3"}),
+            serde_json::json!({"message": "yo", "tags": ["a"]}),
+        ]);
+        let format = |f| resp.format(f).unwrap();
+        assert_eq!(
+            format(OutputFormat::Json),
+            "{\"count\":\"3\",\"message\":\"hi\"}\n{\"message\":\"yo\",\"tags\":[\"a\"]}\n"
+        );
+        assert!(format(OutputFormat::JsonPretty).starts_with("{\n  \"count\": \"3\""));
+        assert_eq!(
+            format(OutputFormat::Yaml),
+            "count: '3'\nmessage: hi\n---\nmessage: yo\ntags:\n- a\n"
+        );
+        assert_eq!(
+            format(OutputFormat::Table),
+            "COUNT  MESSAGE  TAGS\n3      hi\n       yo       [\"a\"]\n"
+        );
+        assert!(format(OutputFormat::Debug).starts_with("Msg("));
+
+        // The only list of messages of a response is its rows.
+        let resp = responses(&[serde_json::json!({
+            "containers": [{"id": "c1", "labels": {"k": "v"}}, {"id": "c22"}]
+        })]);
+        assert_eq!(
+            resp.format(OutputFormat::Table).unwrap(),
+            "ID   LABELS\nc1   {\"k\":\"v\"}\nc22\n"
+        );
+        assert_eq!(responses(&[]).format(OutputFormat::Table).unwrap(), "");
+
+        let query = |q| crate::query::Query::parse(q).unwrap();
+        assert_eq!(
+            resp.format_query(&query(".containers[].id"), OutputFormat::JsonPretty),
+            "c1\nc22\n"
+        );
+        assert_eq!(
+            resp.format_query(&query("$..labels"), OutputFormat::Json),
+            "{\"k\":\"v\"}\n"
+        );
+        assert_eq!(
+            resp.format_query(&query("$..labels"), OutputFormat::Yaml),
+            "{\n  \"k\": \"v\"\n}\n"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Response queries, a path subset shared by JSONPath and jq.
+//! - `$` or `.` is the response.
+//! - `.name`, `['name']` and `["name"]` select a field.
+//! - `[0]` selects a list element, `[-1]` the last one.
+//! - `[*]`, `[]` and `.*` select every element of a list or every field of a message.
+//! - `..name` selects the field at any depth.
+
+use serde_json::Value;
+
+/// A parsed query.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Query(Vec<Segment>);
+
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Field(String),
+    Index(i64),
+    Wildcard,
+    /// The value and every value nested in it.
+    Descendants,
+}
+
+impl Query {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let err = |msg: &str| format!("invalid query '{s}': {msg}");
+        let mut rest = s.trim();
+        rest = rest.strip_prefix('$').unwrap_or(rest);
+        if rest == "." {
+            rest = "";
+        }
+        let mut segments = Vec::new();
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix("..") {
+                segments.push(Segment::Descendants);
+                rest = after;
+                if !rest.starts_with('[') {
+                    rest = name(rest, &mut segments).ok_or_else(|| err("expected a field name"))?;
+                }
+            } else if let Some(after) = rest.strip_prefix('.') {
+                rest = after;
+                // jq writes `.[0]`.
+                if !rest.starts_with('[') {
+                    rest = name(rest, &mut segments).ok_or_else(|| err("expected a field name"))?;
+                }
+            } else if let Some(after) = rest.strip_prefix('[') {
+                let end = after.find(']').ok_or_else(|| err("missing ']'"))?;
+                segments.push(bracket(after[..end].trim()).ok_or_else(|| err("invalid index"))?);
+                rest = &after[end + 1..];
+            } else {
+                return Err(err("expected '.' or '['"));
+            }
+        }
+        Ok(Self(segments))
+    }
+
+    /// The values the query selects in `value`, in document order.
+    pub fn select<'a>(&self, value: &'a Value) -> Vec<&'a Value> {
+        let mut values = vec![value];
+        for segment in &self.0 {
+            let mut next = Vec::new();
+            for value in values {
+                match (segment, value) {
+                    (Segment::Field(name), Value::Object(obj)) => next.extend(obj.get(name)),
+                    (Segment::Index(i), Value::Array(items)) => {
+                        let i = if *i < 0 { items.len() as i64 + i } else { *i };
+                        next.extend(usize::try_from(i).ok().and_then(|i| items.get(i)));
+                    }
+                    (Segment::Wildcard, Value::Array(items)) => next.extend(items),
+                    (Segment::Wildcard, Value::Object(obj)) => next.extend(obj.values()),
+                    (Segment::Descendants, value) => descendants(value, &mut next),
+                    _ => {}
+                }
+            }
+            values = next;
+        }
+        values
+    }
+}
+
+/// Push the field name at the start of `s`, or `*`, and return the rest.
+fn name<'a>(s: &'a str, segments: &mut Vec<Segment>) -> Option<&'a str> {
+    let end = s.find(['.', '[']).unwrap_or(s.len());
+    let segment = match &s[..end] {
+        "" => return None,
+        "*" => Segment::Wildcard,
+        name => Segment::Field(name.to_string()),
+    };
+    segments.push(segment);
+    Some(&s[end..])
+}
+
+fn bracket(s: &str) -> Option<Segment> {
+    if s.is_empty() || s == "*" {
+        return Some(Segment::Wildcard);
+    }
+    for quote in ['\'', '"'] {
+        if let Some(name) = s.strip_prefix(quote).and_then(|s| s.strip_suffix(quote)) {
+            return Some(Segment::Field(name.to_string()));
+        }
+    }
+    s.parse().ok().map(Segment::Index)
+}
+
+fn descendants<'a>(value: &'a Value, out: &mut Vec<&'a Value>) {
+    out.push(value);
+    match value {
+        Value::Array(items) => items.iter().for_each(|v| descendants(v, out)),
+        Value::Object(obj) => obj.values().for_each(|v| descendants(v, out)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query() {
+        let value = serde_json::json!({
+            "containers": [
+                {"id": "c1", "labels": {"app": "a"}},
+                {"id": "c2", "image": "busybox"},
+            ],
+            "@type": "t",
+        });
+        let select = |q: &str| {
+            Query::parse(q)
+                .unwrap()
+                .select(&value)
+                .into_iter()
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(select("."), vec![value.clone()]);
+        assert_eq!(select("$"), vec![value.clone()]);
+        assert_eq!(select("$.containers[0].id"), vec!["c1"]);
+        assert_eq!(select(".containers[-1].id"), vec!["c2"]);
+        assert_eq!(select(".containers[].id"), vec!["c1", "c2"]);
+        assert_eq!(select("$.containers[*].image"), vec!["busybox"]);
+        assert_eq!(select("$['containers'][1][\"id\"]"), vec!["c2"]);
+        assert_eq!(select(".containers.[0].labels.*"), vec!["a"]);
+        assert_eq!(select("$..id"), vec!["c1", "c2"]);
+        assert_eq!(select(".@type"), vec!["t"]);
+        assert!(select(".containers[5]").is_empty());
+        assert!(select(".missing.id").is_empty());
+
+        for q in [
+            "containers",
+            ".containers[0",
+            ".a.",
+            ".a[x]",
+            "$..",
+            ".a..[b",
+        ] {
+            assert!(Query::parse(q).is_err(), "{q}");
+        }
+    }
+}
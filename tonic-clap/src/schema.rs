@@ -0,0 +1,162 @@
+//! Static description of proto messages, generated by the `TonicClap` derive.
+//! The derive reads the rust field types and the `#[prost(..)]` attributes,
+//! plus the `#[tonic_clap(..)]` attributes `tonic-clap-build` adds for proto
+//! information prost does not keep, e.g. `google.api.field_behavior`.
+
+use std::borrow::Cow;
+
+use heck::{ToLowerCamelCase, ToSnakeCase};
+
+use crate::flags::Flags;
+
+/// Max number of path segments in a message field flag when the request does not set one.
+pub const DEFAULT_MAX_FLAG_DEPTH: usize = 8;
+
+/// Separator of the path segments in a message field flag when the request does not set one.
+pub const DEFAULT_FLAG_SEPARATOR: char = '.';
+
+#[derive(Debug)]
+pub struct MessageSchema {
+    /// Rust type name, e.g. `HelloRequest2`.
+    pub name: &'static str,
+    /// Fully qualified proto name, e.g. `helloworld.HelloRequest2`, if known.
+    pub full_name: Option<&'static str>,
+    /// Max number of path segments in a message field flag, e.g. `--a.b.c` is 3.
+    /// Messages nested deeper, or recursively, take a single JSON flag instead.
+    /// Read from the request message, see `tonic_clap_build::Builder::max_flag_depth`.
+    pub max_flag_depth: Option<usize>,
+    /// Separator of the path segments in a message field flag, e.g. `.` in `--a.b`.
+    /// Read from the request message, see `tonic_clap_build::Builder::flag_separator`.
+    pub flag_separator: Option<char>,
+    /// Struct fields in declaration order. For oneofs, one field per variant.
+    pub fields: &'static [Field],
+}
+
+#[derive(Debug)]
+pub struct Field {
+    /// Rust field name, or variant name in oneofs.
+    pub name: &'static str,
+    pub kind: Kind,
+    pub label: Label,
+    pub behavior: Option<FieldBehavior>,
+    /// Proto field name, if it is not the snake_case name.
+    pub proto_name: Option<&'static str>,
+    /// proto3 JSON name, if it is not the lowerCamelCase proto name.
+    pub json_name: Option<&'static str>,
+    /// Proto comment, shown in the option help.
+    pub doc: Option<&'static str>,
+}
+
+impl Field {
+    /// Proto field name, e.g. `one_of_1` for the variant `OneOf1`.
+    pub fn proto_name(&self) -> Cow<'static, str> {
+        match self.proto_name {
+            Some(name) => Cow::Borrowed(name),
+            None => Cow::Owned(self.name.to_snake_case()),
+        }
+    }
+
+    /// Name of the field in proto3 JSON, e.g. `oneOf1`.
+    pub fn json_name(&self) -> Cow<'static, str> {
+        match self.json_name {
+            Some(name) => Cow::Borrowed(name),
+            None => Cow::Owned(self.proto_name().to_lower_camel_case()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Label {
+    /// Proto3 implicit presence, oneof variants and proto2 required fields.
+    Singular,
+    /// `Option<T>`, i.e. messages and proto3 optional fields.
+    Optional,
+    Repeated,
+    /// Map with keys of this scalar type. The kind is the value type.
+    Map(Scalar),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Kind {
+    Scalar(Scalar),
+    /// Proto enum stored by prost as i32.
+    Enum(fn() -> &'static EnumSchema),
+    Message(fn() -> &'static MessageSchema),
+    Oneof(fn() -> &'static MessageSchema),
+}
+
+/// Rust types of proto scalars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scalar {
+    String,
+    Bool,
+    I32,
+    I64,
+    U32,
+    U64,
+    F32,
+    F64,
+    Bytes,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct EnumSchema {
+    /// Rust type name, e.g. `EnumOk`.
+    pub name: &'static str,
+    /// Proto value names and numbers in declaration order.
+    pub values: &'static [(&'static str, i32)],
+}
+
+impl EnumSchema {
+    /// Resolve a value name or number to the enum number.
+    /// Numbers not declared in the enum are rejected.
+    pub fn parse_value(&self, s: &str) -> Option<i32> {
+        if let Some((_, v)) = self.values.iter().find(|(n, _)| *n == s) {
+            return Some(*v);
+        }
+        let v = s.parse::<i32>().ok()?;
+        self.values.iter().any(|(_, n)| *n == v).then_some(v)
+    }
+}
+
+/// `google.api.field_behavior` of a field. proto2 `required` is `Required` too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldBehavior {
+    /// Required flag, when the enclosing messages are required as well.
+    Required,
+    /// Set by the server, not a request flag.
+    OutputOnly,
+}
+
+/// A proto message struct.
+pub trait Message: Default + 'static {
+    fn schema() -> &'static MessageSchema;
+
+    /// Set the fields whose flags are given.
+    fn merge_flags(&mut self, flags: &Flags<'_>) -> Result<(), clap::Error>;
+}
+
+/// The enum of a proto oneof, held as `Option<Self>` by its message.
+pub trait Oneof: Sized + 'static {
+    fn schema() -> &'static MessageSchema;
+
+    /// Set the variant whose flags are given.
+    fn merge_flags(this: &mut Option<Self>, flags: &Flags<'_>) -> Result<(), clap::Error>;
+}
+
+/// A proto enum.
+pub trait Enumeration {
+    fn schema() -> &'static EnumSchema;
+}
+
+/// Messages and oneofs, which a field holds by type alone.
+/// The derive uses it for fields it cannot tell apart from the rust type.
+pub trait Nested: Sized {
+    const KIND: Kind;
+
+    fn merge_optional(
+        field: &mut Option<Self>,
+        flags: &Flags<'_>,
+        name: &str,
+    ) -> Result<(), clap::Error>;
+}
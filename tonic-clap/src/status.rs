@@ -0,0 +1,223 @@
+//! Failed calls: the report printed for them and the process exit code.
+//! The report decodes the `google.rpc.Status` in `grpc-status-details-bin`.
+//! `ErrorInfo`, `BadRequest` and `RetryInfo` details are always decoded,
+//! other details if the app compiles their messages (see [`crate::any`]).
+
+use std::collections::HashMap;
+
+use heck::ToShoutySnakeCase;
+use prost::Message;
+
+/// Exit code of a failed call is this plus the `tonic::Code`, e.g. 69 for NOT_FOUND.
+pub const EXIT_CODE_STATUS_BASE: u8 = 64;
+
+/// Exit code of a call that failed with `code`.
+pub fn exit_code(code: tonic::Code) -> u8 {
+    EXIT_CODE_STATUS_BASE + code as u8
+}
+
+/// `code: message` of the status, then one line per detail.
+pub fn report(status: &tonic::Status) -> String {
+    let code = format!("{:?}", status.code()).to_shouty_snake_case();
+    let mut out = format!("{code}: {}", status.message());
+    let details = match RpcStatus::decode(status.details()) {
+        Ok(rpc) => rpc.details,
+        Err(e) => {
+            out += &format!("\n  invalid grpc-status-details-bin: {e}");
+            Vec::new()
+        }
+    };
+    for detail in details {
+        out += "\n  ";
+        out += &describe(&detail);
+    }
+    out
+}
+
+fn describe(detail: &Any) -> String {
+    let full_name = detail.type_url.rsplit('/').next().unwrap_or_default();
+    let value = detail.value.as_slice();
+    match full_name {
+        "google.rpc.ErrorInfo" => match ErrorInfo::decode(value) {
+            Ok(info) => {
+                let mut out = format!("ErrorInfo: reason {}, domain {}", info.reason, info.domain);
+                let mut metadata = info.metadata.into_iter().collect::<Vec<_>>();
+                metadata.sort();
+                for (key, value) in metadata {
+                    out += &format!(", {key}={value}");
+                }
+                out
+            }
+            Err(e) => format!("{full_name}: {e}"),
+        },
+        "google.rpc.BadRequest" => match BadRequest::decode(value) {
+            Ok(bad) => {
+                let violations = bad
+                    .field_violations
+                    .iter()
+                    .map(|v| format!("{}: {}", v.field, v.description));
+                format!("BadRequest: {}", violations.collect::<Vec<_>>().join("; "))
+            }
+            Err(e) => format!("{full_name}: {e}"),
+        },
+        "google.rpc.RetryInfo" => match RetryInfo::decode(value) {
+            Ok(RetryInfo {
+                retry_delay: Some(delay),
+            }) => format!(
+                "RetryInfo: retry after {}",
+                crate::wkt::format_duration(delay.seconds, delay.nanos)
+            ),
+            Ok(_) => "RetryInfo: retry".to_string(),
+            Err(e) => format!("{full_name}: {e}"),
+        },
+        _ => match crate::any::find(full_name) {
+            Some(t) => match t.decode(value) {
+                Ok(json) => format!(
+                    "{full_name}: {}",
+                    crate::json::to_proto_json(t.schema(), json)
+                ),
+                Err(e) => format!("{full_name}: {e}"),
+            },
+            None => format!("{} ({} bytes)", detail.type_url, value.len()),
+        },
+    }
+}
+
+/// `google.rpc.Status`.
+#[derive(Clone, PartialEq, prost::Message)]
+struct RpcStatus {
+    #[prost(int32, tag = "1")]
+    code: i32,
+    #[prost(string, tag = "2")]
+    message: String,
+    #[prost(message, repeated, tag = "3")]
+    details: Vec<Any>,
+}
+
+/// `google.protobuf.Any`.
+#[derive(Clone, PartialEq, prost::Message)]
+struct Any {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+/// `google.protobuf.Duration`.
+#[derive(Clone, PartialEq, prost::Message)]
+struct Duration {
+    #[prost(int64, tag = "1")]
+    seconds: i64,
+    #[prost(int32, tag = "2")]
+    nanos: i32,
+}
+
+/// `google.rpc.ErrorInfo`.
+#[derive(Clone, PartialEq, prost::Message)]
+struct ErrorInfo {
+    #[prost(string, tag = "1")]
+    reason: String,
+    #[prost(string, tag = "2")]
+    domain: String,
+    #[prost(map = "string, string", tag = "3")]
+    metadata: HashMap<String, String>,
+}
+
+/// `google.rpc.BadRequest`.
+#[derive(Clone, PartialEq, prost::Message)]
+struct BadRequest {
+    #[prost(message, repeated, tag = "1")]
+    field_violations: Vec<FieldViolation>,
+}
+
+/// `google.rpc.BadRequest.FieldViolation`.
+#[derive(Clone, PartialEq, prost::Message)]
+struct FieldViolation {
+    #[prost(string, tag = "1")]
+    field: String,
+    #[prost(string, tag = "2")]
+    description: String,
+}
+
+/// `google.rpc.RetryInfo`.
+#[derive(Clone, PartialEq, prost::Message)]
+struct RetryInfo {
+    #[prost(message, optional, tag = "1")]
+    retry_delay: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn any(name: &str, msg: impl Message) -> Any {
+        Any {
+            type_url: format!("type.googleapis.com/{name}"),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_report() {
+        let status = tonic::Status::not_found("no container c1");
+        assert_eq!(report(&status), "NOT_FOUND: no container c1");
+        assert_eq!(exit_code(status.code()), 69);
+        assert_eq!(exit_code(tonic::Code::Unavailable), 78);
+
+        let details = RpcStatus {
+            code: tonic::Code::InvalidArgument as i32,
+            message: "bad".into(),
+            details: vec![
+                any(
+                    "google.rpc.ErrorInfo",
+                    ErrorInfo {
+                        reason: "EMPTY_ID".into(),
+                        domain: "example.com".into(),
+                        metadata: HashMap::from([
+                            ("b".into(), "2".into()),
+                            ("a".into(), "1".into()),
+                        ]),
+                    },
+                ),
+                any(
+                    "google.rpc.BadRequest",
+                    BadRequest {
+                        field_violations: vec![
+                            FieldViolation {
+                                field: "id".into(),
+                                description: "must not be empty".into(),
+                            },
+                            FieldViolation {
+                                field: "name".into(),
+                                description: "too long".into(),
+                            },
+                        ],
+                    },
+                ),
+                any(
+                    "google.rpc.RetryInfo",
+                    RetryInfo {
+                        retry_delay: Some(Duration {
+                            seconds: 1,
+                            nanos: 500_000_000,
+                        }),
+                    },
+                ),
+                any("pkg.Unknown", Duration::default()),
+            ],
+        };
+        let status = tonic::Status::with_details(
+            tonic::Code::InvalidArgument,
+            "bad",
+            details.encode_to_vec().into(),
+        );
+        assert_eq!(
+            report(&status),
+            "INVALID_ARGUMENT: bad\n  \
+             ErrorInfo: reason EMPTY_ID, domain example.com, a=1, b=2\n  \
+             BadRequest: id: must not be empty; name: too long\n  \
+             RetryInfo: retry after 1.500s\n  \
+             type.googleapis.com/pkg.Unknown (0 bytes)"
+        );
+    }
+}
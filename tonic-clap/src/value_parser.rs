@@ -0,0 +1,200 @@
+// Custom clap value parsers for proto types that do not map to a rust primitive.
+
+use std::path::PathBuf;
+
+use base64::Engine;
+use clap::builder::{PossibleValue, PossibleValuesParser, TypedValueParser, ValueParser};
+
+use serde_json::Value;
+
+use crate::{schema::EnumSchema, visit::TCFieldTypePrimitive};
+
+/// Accepts proto enum value names and declared numbers, and yields the i32 number.
+/// Only the names are shown in help and completions.
+pub(crate) fn enum_value_parser(proto_enum: &'static EnumSchema) -> ValueParser {
+    let possible_values = proto_enum.values.iter().flat_map(|(name, number)| {
+        [
+            PossibleValue::new(*name),
+            PossibleValue::new(number.to_string()).hide(true),
+        ]
+    });
+    PossibleValuesParser::new(possible_values)
+        .map(move |s| {
+            proto_enum
+                .parse_value(&s)
+                .expect("value is validated by possible values")
+        })
+        .into()
+}
+
+/// Proto bytes input. Base64 by default,
+/// `hex:` prefix for hex and `@` prefix for the contents of a file.
+pub(crate) fn bytes_value_parser() -> ValueParser {
+    ValueParser::new(parse_bytes)
+}
+
+/// Value of a bytes flag. Files are read when the message is built,
+/// not while the args are parsed, see [`BytesArg::read`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum BytesArg {
+    Data(Vec<u8>),
+    /// `@path`.
+    File(PathBuf),
+}
+
+impl BytesArg {
+    pub(crate) fn read(&self) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Data(data) => Ok(data.clone()),
+            Self::File(path) => {
+                std::fs::read(path).map_err(|e| format!("cannot read file {}: {e}", path.display()))
+            }
+        }
+    }
+}
+
+fn parse_bytes(s: &str) -> Result<BytesArg, String> {
+    if let Some(path) = s.strip_prefix('@') {
+        return Ok(BytesArg::File(path.into()));
+    }
+    let data = match s.strip_prefix("hex:") {
+        Some(hex) => hex::decode(hex).map_err(|e| format!("invalid hex: {e}"))?,
+        None => base64::engine::general_purpose::STANDARD
+            .decode(s)
+            .map_err(|e| format!("invalid base64: {e}"))?,
+    };
+    Ok(BytesArg::Data(data))
+}
+
+/// Any JSON value. Used for messages that cannot be expanded into flags.
+pub(crate) fn json_value_parser() -> ValueParser {
+    ValueParser::new(|s: &str| {
+        serde_json::from_str::<Value>(s).map_err(|e| format!("invalid json: {e}"))
+    })
+}
+
+/// One `key=value` map entry. Yields the key as a string, as JSON object keys are strings,
+/// and the value as the JSON serde expects for the value type.
+pub(crate) fn map_entry_value_parser(
+    key_type: &TCFieldTypePrimitive,
+    value_type: &TCFieldTypePrimitive,
+) -> ValueParser {
+    let key_type = key_type.clone();
+    let value_type = value_type.clone();
+    ValueParser::new(move |s: &str| -> Result<(String, Value), String> {
+        let (k, v) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected KEY=VALUE, got '{s}'"))?;
+        let key = match parse_json_value(&key_type, k)? {
+            Value::String(k) => k,
+            // serde reads numeric and bool keys from their string form.
+            other => other.to_string(),
+        };
+        Ok((key, parse_json_value(&value_type, v)?))
+    })
+}
+
+/// Parse a single arg string into the JSON value serde expects for the type.
+pub(crate) fn parse_json_value(
+    field_type: &TCFieldTypePrimitive,
+    s: &str,
+) -> Result<Value, String> {
+    fn num<T>(s: &str) -> Result<T, String>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        s.parse::<T>()
+            .map_err(|e| format!("invalid value '{s}': {e}"))
+    }
+
+    let value = match field_type {
+        TCFieldTypePrimitive::String => Value::String(s.to_string()),
+        TCFieldTypePrimitive::U32 => num::<u32>(s)?.into(),
+        TCFieldTypePrimitive::U64 => num::<u64>(s)?.into(),
+        TCFieldTypePrimitive::I32 => num::<i32>(s)?.into(),
+        TCFieldTypePrimitive::I64 => num::<i64>(s)?.into(),
+        TCFieldTypePrimitive::F32 => num::<f32>(s)?.into(),
+        TCFieldTypePrimitive::F64 => num::<f64>(s)?.into(),
+        TCFieldTypePrimitive::Bool => num::<bool>(s)?.into(),
+        TCFieldTypePrimitive::Enum(proto_enum) => proto_enum
+            .parse_value(s)
+            .ok_or_else(|| {
+                let names = proto_enum
+                    .values
+                    .iter()
+                    .map(|(n, _)| *n)
+                    .collect::<Vec<_>>();
+                format!("invalid value '{s}', possible values: {}", names.join(", "))
+            })?
+            .into(),
+        // files stay `@path`, which is not base64, until the message is built.
+        TCFieldTypePrimitive::Bytes => match parse_bytes(s)? {
+            BytesArg::Data(data) => data.into(),
+            BytesArg::File(_) => Value::String(s.to_string()),
+        },
+        TCFieldTypePrimitive::Json(_) | TCFieldTypePrimitive::Any => {
+            serde_json::from_str(s).map_err(|e| format!("invalid json: {e}"))?
+        }
+        TCFieldTypePrimitive::Timestamp => crate::wkt::parse_timestamp(s)?,
+        TCFieldTypePrimitive::Duration => crate::wkt::parse_duration(s)?,
+        TCFieldTypePrimitive::Struct(struct_type) => struct_type.parse(s)?,
+        TCFieldTypePrimitive::Wrapper(inner) => {
+            serde_json::json!({ "value": parse_json_value(inner, s)? })
+        }
+        TCFieldTypePrimitive::Vec(_) | TCFieldTypePrimitive::Map(..) => {
+            return Err(format!("nested collection is not supported: '{s}'"));
+        }
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bytes() {
+        let data = |s: &str| parse_bytes(s).unwrap().read().unwrap();
+        assert_eq!(data("aGVsbG8="), b"hello");
+        assert_eq!(data("hex:68656c6c6f"), b"hello");
+        assert_eq!(data(""), b"");
+        assert!(parse_bytes("hex:zz").is_err());
+        assert!(parse_bytes("not base64!").is_err());
+        // the file is only read with the message.
+        let file = parse_bytes("@/path/does/not/exist").unwrap();
+        assert_eq!(file, BytesArg::File("/path/does/not/exist".into()));
+        assert!(file.read().is_err());
+    }
+
+    #[test]
+    fn test_parse_json_value() {
+        static PROTO_ENUM: EnumSchema = EnumSchema {
+            name: "E",
+            values: &[("A", 0), ("B", 5)],
+        };
+        let proto_enum = &PROTO_ENUM;
+        assert_eq!(
+            parse_json_value(&TCFieldTypePrimitive::Enum(proto_enum), "B").unwrap(),
+            Value::from(5)
+        );
+        assert!(parse_json_value(&TCFieldTypePrimitive::Enum(proto_enum), "1").is_err());
+        assert_eq!(
+            parse_json_value(&TCFieldTypePrimitive::U32, "7").unwrap(),
+            Value::from(7)
+        );
+        assert!(parse_json_value(&TCFieldTypePrimitive::U32, "-7").is_err());
+        assert_eq!(
+            parse_json_value(&TCFieldTypePrimitive::Bytes, "hex:0102").unwrap(),
+            Value::from(vec![1, 2])
+        );
+        assert_eq!(
+            parse_json_value(&TCFieldTypePrimitive::Bytes, "@data.bin").unwrap(),
+            Value::from("@data.bin")
+        );
+        assert_eq!(
+            parse_json_value(&TCFieldTypePrimitive::Json("M".into()), r#"{"a":1}"#).unwrap(),
+            serde_json::json!({"a": 1})
+        );
+    }
+}
@@ -1,6 +1,18 @@
 #![allow(dead_code)]
 
-use bevy_reflect::{EnumInfo, TypeInfo};
+use std::{
+    collections::HashMap,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use crate::{
+    Error,
+    schema::{
+        DEFAULT_MAX_FLAG_DEPTH, EnumSchema, Field, FieldBehavior, Kind, Label, MessageSchema,
+        Scalar,
+    },
+    wkt::StructType,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum TCFieldType {
@@ -13,14 +25,16 @@ pub(crate) enum TCFieldType {
         name: String, // Only for debugging.
         fields: Vec<TCStructField>,
     },
-    Unknown(String),
+    /// Proto oneof, one field per variant. At most one variant can be set.
+    OneOf {
+        fields: Vec<TCStructField>,
+    },
 }
 
 // TODO: this may not be exhaustive.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum TCFieldTypePrimitive {
     String,
-    U8, // Byte
     U32,
     U64,
     I32,
@@ -28,7 +42,25 @@ pub(crate) enum TCFieldTypePrimitive {
     F32,
     F64,
     Bool,
+    /// Proto enum stored by prost as i32.
+    Enum(&'static EnumSchema),
+    /// Proto bytes stored by prost as `Vec<u8>`.
+    Bytes,
+    /// Message passed as a JSON value. Holds the type name for display.
+    Json(String),
+    /// google.protobuf.Timestamp passed as RFC 3339.
+    Timestamp,
+    /// google.protobuf.Duration passed as e.g. `1h30m`.
+    Duration,
+    /// google.protobuf.Any passed as JSON with an `@type` key.
+    Any,
+    /// google.protobuf.Struct, Value or ListValue passed as plain JSON.
+    Struct(StructType),
+    /// google.protobuf wrapper, e.g. Int64Value, passed as the wrapped scalar.
+    Wrapper(Box<TCFieldTypePrimitive>),
     Vec(Box<TCFieldType>),
+    /// Proto map. Key is always a scalar primitive.
+    Map(Box<TCFieldType>, Box<TCFieldType>),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -36,209 +68,248 @@ pub(crate) struct TCStructField {
     pub prefix: Vec<String>, // field path from the root struct to this field.
     pub field_name: String,
     pub field_type: TCFieldType,
+    /// Proto comment of the field.
+    pub doc: Option<&'static str>,
 }
 
-fn parse_type_path(type_info: &TypeInfo, prefix: Vec<String>) -> TCFieldType {
-    let type_path = strip_namespace(type_info.type_path());
-    // set all to required and reset it in outter optional case.
-    match type_path {
-        "String" => TCFieldType::Primitive {
-            field_type: TCFieldTypePrimitive::String,
-            required: true,
-        },
-        "i32" => TCFieldType::Primitive {
-            field_type: TCFieldTypePrimitive::I32,
-            required: true,
-        },
-        "i64" => TCFieldType::Primitive {
-            field_type: TCFieldTypePrimitive::I64,
-            required: true,
-        },
-        "f32" => TCFieldType::Primitive {
-            field_type: TCFieldTypePrimitive::F32,
-            required: true,
-        },
-        "f64" => TCFieldType::Primitive {
-            field_type: TCFieldTypePrimitive::F64,
-            required: true,
-        },
-        "bool" => TCFieldType::Primitive {
-            field_type: TCFieldTypePrimitive::Bool,
-            required: true,
-        },
-        "u8" => TCFieldType::Primitive {
-            field_type: TCFieldTypePrimitive::U8,
+/// State of one walk over a request type.
+struct Walker {
+    max_depth: usize,
+    /// Messages being expanded, outermost first.
+    ancestors: Vec<&'static MessageSchema>,
+    /// Whether the fields being expanded are all required.
+    required: bool,
+    /// Why fields were left out of the cli.
+    skipped: Vec<String>,
+}
+
+impl Walker {
+    fn new(schema: &MessageSchema) -> Self {
+        Self {
+            max_depth: schema.max_flag_depth.unwrap_or(DEFAULT_MAX_FLAG_DEPTH),
+            ancestors: Vec::new(),
             required: true,
+            skipped: Vec::new(),
+        }
+    }
+
+    /// A field is a required flag only if its enclosing message fields are required too.
+    fn parse_field(
+        &mut self,
+        field: &Field,
+        prefix: Vec<String>,
+        required: bool,
+    ) -> Result<TCFieldType, Error> {
+        let required = self.required && required;
+        let outer = std::mem::replace(&mut self.required, required);
+        let field_type = parse_labeled(field, prefix, self);
+        self.required = outer;
+        let mut field_type = field_type?;
+        field_type.set_required(required);
+        Ok(field_type)
+    }
+
+    /// Unsupported fields are left out of the cli instead of failing the whole request.
+    fn parse_field_or_skip(
+        &mut self,
+        field: &Field,
+        prefix: Vec<String>,
+        required: bool,
+    ) -> Option<TCFieldType> {
+        self.parse_field(field, prefix, required)
+            .inspect_err(|e| self.skipped.push(e.to_string()))
+            .ok()
+    }
+
+    /// Recursive or too deep messages are not expanded into flags.
+    fn expands(&self, schema: &MessageSchema, prefix: &[String]) -> bool {
+        prefix.is_empty()
+            || (prefix.len() < self.max_depth
+                && !self.ancestors.iter().any(|a| std::ptr::eq(*a, schema)))
+    }
+}
+
+fn parse_labeled(
+    field: &Field,
+    prefix: Vec<String>,
+    walker: &mut Walker,
+) -> Result<TCFieldType, Error> {
+    // set all to required and reset it in outter optional case.
+    let field_type = match field.label {
+        Label::Singular => parse_kind(field.kind, prefix, walker)?,
+        Label::Optional => TCFieldType::Option(Box::new(parse_kind(field.kind, prefix, walker)?)),
+        // Vec is currently always optional.
+        Label::Repeated => TCFieldType::Primitive {
+            field_type: TCFieldTypePrimitive::Vec(Box::new(parse_element(field.kind, &prefix)?)),
+            required: false,
         },
-        "u32" => TCFieldType::Primitive {
-            field_type: TCFieldTypePrimitive::U32,
-            required: true,
+        // Map is a single repeatable `key=value` arg.
+        Label::Map(key) => TCFieldType::Primitive {
+            field_type: TCFieldTypePrimitive::Map(
+                Box::new(TCFieldType::Primitive {
+                    field_type: scalar(key),
+                    required: true,
+                }),
+                Box::new(parse_element(field.kind, &prefix)?),
+            ),
+            required: false,
         },
-        "u64" => TCFieldType::Primitive {
-            field_type: TCFieldTypePrimitive::U64,
-            required: true,
+    };
+    Ok(field_type)
+}
+
+fn parse_kind(kind: Kind, prefix: Vec<String>, walker: &mut Walker) -> Result<TCFieldType, Error> {
+    let field_type = match kind {
+        Kind::Scalar(s) => scalar(s),
+        Kind::Enum(schema) => TCFieldTypePrimitive::Enum(schema()),
+        Kind::Message(schema) => match well_known_type(schema()) {
+            Some(well_known) => well_known,
+            None => return parse_struct(schema(), prefix, walker),
         },
-        _ if type_path.starts_with("Vec<") && type_path.ends_with(">") => {
-            let inner = &type_path[4..type_path.len() - 1];
-            let list_info = if let bevy_reflect::TypeInfo::List(list_info) = type_info {
-                list_info
-            } else {
-                panic!("not an Vec: {type_info:?}");
-            };
+        Kind::Oneof(schema) => return parse_oneof(schema(), prefix, walker),
+    };
+    Ok(TCFieldType::Primitive {
+        field_type,
+        required: true,
+    })
+}
 
-            let inner_type_info = list_info.item_info().unwrap_or_else(|| {
-                panic!("Could not get item info for list: {list_info:?}");
-            });
-            assert_eq!(
-                strip_namespace(inner),
-                strip_namespace(inner_type_info.type_path())
-            );
-            let inner_type = parse_type_path(inner_type_info, prefix.clone());
-            // Vec is currently always optional.
-            TCFieldType::Primitive {
-                field_type: TCFieldTypePrimitive::Vec(Box::new(inner_type)),
-                required: false,
-            }
+/// Messages inside collections cannot be expanded into flags,
+/// so they are taken as a JSON value instead.
+fn parse_element(kind: Kind, prefix: &[String]) -> Result<TCFieldType, Error> {
+    let field_type = match kind {
+        Kind::Scalar(s) => scalar(s),
+        Kind::Enum(schema) => TCFieldTypePrimitive::Enum(schema()),
+        Kind::Message(schema) => well_known_type(schema())
+            .unwrap_or_else(|| TCFieldTypePrimitive::Json(schema().name.to_string())),
+        Kind::Oneof(schema) => {
+            return Err(Error::unsupported(
+                schema().name,
+                prefix,
+                "oneof in a repeated or map field",
+            ));
         }
-        _ if type_path.starts_with("Option<") && type_path.ends_with(">") => {
-            let inner = &type_path[7..type_path.len() - 1];
+    };
+    Ok(TCFieldType::Primitive {
+        field_type,
+        required: true,
+    })
+}
 
-            let enum_info = if let bevy_reflect::TypeInfo::Enum(enum_info) = type_info {
-                enum_info
-            } else {
-                panic!("not an option.");
-            };
-            assert!(enum_info.variant("Some").is_some() && enum_info.variant("None").is_some());
-
-            // Get the Some variant which contains the inner type
-            let some_variant = enum_info
-                .variant("Some")
-                .unwrap_or_else(|| panic!("Could not find Some variant for: {enum_info:?}"));
-            // Option's Some variant is a tuple variant with one field containing T
-            let tuple_variant = some_variant.as_tuple_variant().unwrap_or_else(|_| {
-                panic!("Some variant is not a tuple variant for: {enum_info:?}",)
-            });
-            let inner_field = tuple_variant.field_at(0).unwrap_or_else(|| {
-                panic!("Could not access inner field at index 0 for: {enum_info:?}",)
-            });
-            // Recursively call with the inner type's info
-            let inner_type_info = inner_field.type_info().unwrap_or_else(|| {
-                panic!("Could not get type info for inner field: {inner_field:?}",)
-            });
-            assert_eq!(
-                strip_namespace(inner),
-                strip_namespace(inner_type_info.type_path())
-            );
-            let mut inner_type = parse_type_path(inner_type_info, prefix.clone());
-            // Set the current inner type as optional only when it is not nested.
-            // TODO: detect nested required.
-            if inner_type.is_primitive()
-                && prefix.is_empty()
-                && let TCFieldType::Primitive { required, .. } = &mut inner_type
-            {
-                *required = false;
-            }
-            TCFieldType::Option(Box::new(inner_type))
-        }
+fn scalar(scalar: Scalar) -> TCFieldTypePrimitive {
+    match scalar {
+        Scalar::String => TCFieldTypePrimitive::String,
+        Scalar::Bool => TCFieldTypePrimitive::Bool,
+        Scalar::I32 => TCFieldTypePrimitive::I32,
+        Scalar::I64 => TCFieldTypePrimitive::I64,
+        Scalar::U32 => TCFieldTypePrimitive::U32,
+        Scalar::U64 => TCFieldTypePrimitive::U64,
+        Scalar::F32 => TCFieldTypePrimitive::F32,
+        Scalar::F64 => TCFieldTypePrimitive::F64,
+        Scalar::Bytes => TCFieldTypePrimitive::Bytes,
+    }
+}
+
+/// Well known messages that are passed as a single formatted value.
+/// They are compiled from google/protobuf, so match on the proto name.
+pub(crate) fn well_known_type(schema: &MessageSchema) -> Option<TCFieldTypePrimitive> {
+    let name = schema.full_name?.strip_prefix("google.protobuf.")?;
+    match name {
+        "Timestamp" => Some(TCFieldTypePrimitive::Timestamp),
+        "Duration" => Some(TCFieldTypePrimitive::Duration),
+        "Any" => Some(TCFieldTypePrimitive::Any),
         _ => {
-            // map is not supported yet
-            if matches!(type_info, bevy_reflect::TypeInfo::Map(_)) {
-                TCFieldType::Unknown(type_info.type_path().to_string())
-            } else if let bevy_reflect::TypeInfo::Opaque(_) = type_info {
-                panic!(
-                    "Opaque type is not supported: {type_info:?}, prefix: {prefix:?}. Primitive type needs to be added?"
-                );
-            } else if let bevy_reflect::TypeInfo::Enum(enum_info) = type_info {
-                parse_enum_to_struct(enum_info, prefix)
-            } else {
-                // assume it is a struct.
-                parse_struct(type_info, prefix)
+            if let Some(struct_type) = StructType::from_name(name) {
+                return Some(TCFieldTypePrimitive::Struct(struct_type));
             }
+            let wrapped = match name {
+                "DoubleValue" => TCFieldTypePrimitive::F64,
+                "FloatValue" => TCFieldTypePrimitive::F32,
+                "Int64Value" => TCFieldTypePrimitive::I64,
+                "UInt64Value" => TCFieldTypePrimitive::U64,
+                "Int32Value" => TCFieldTypePrimitive::I32,
+                "UInt32Value" => TCFieldTypePrimitive::U32,
+                "BoolValue" => TCFieldTypePrimitive::Bool,
+                "StringValue" => TCFieldTypePrimitive::String,
+                "BytesValue" => TCFieldTypePrimitive::Bytes,
+                _ => return None,
+            };
+            Some(TCFieldTypePrimitive::Wrapper(Box::new(wrapped)))
         }
     }
 }
 
-// ignore namespace. If it is nested type only strips the outermost layer
-fn strip_namespace(type_path: &str) -> &str {
-    // find the first <
-    let end = type_path.find('<').unwrap_or(type_path.len());
-
-    // find the first :: before <
-    let first_colon = type_path[..end].rfind("::");
-    let index = match first_colon {
-        Some(pos) => std::cmp::min(pos + 2, type_path.len()),
-        None => 0,
-    };
-    &type_path[index..]
-}
-
-fn parse_struct(type_info: &TypeInfo, prefix: Vec<String>) -> TCFieldType {
-    let struct_info = if let TypeInfo::Struct(info) = type_info {
-        info
-    } else {
-        panic!("expect a struct at {prefix:?} : {type_info:?}");
-    };
+fn parse_struct(
+    schema: &'static MessageSchema,
+    prefix: Vec<String>,
+    walker: &mut Walker,
+) -> Result<TCFieldType, Error> {
+    if !walker.expands(schema, &prefix) {
+        return Ok(TCFieldType::Primitive {
+            field_type: TCFieldTypePrimitive::Json(schema.name.to_string()),
+            required: true,
+        });
+    }
+    walker.ancestors.push(schema);
 
-    let prefix_outer = prefix.clone();
-    let fields = struct_info
+    let fields = schema
+        .fields
         .iter()
-        .map(|field| {
-            let field_name = field.name();
-            let mut prefix_inner = prefix_outer.clone();
-            prefix_inner.push(field_name.to_string());
-            let field_type = parse_type_path(field.type_info().unwrap(), prefix_inner.clone());
-            TCStructField {
-                prefix: prefix_outer.clone(), // struct fields them selfs should not contain its own field.
-                field_name: field_name.to_string(),
-                field_type,
+        .filter_map(|field| {
+            if field.behavior == Some(FieldBehavior::OutputOnly) {
+                return None;
             }
+            let mut prefix_inner = prefix.clone();
+            prefix_inner.push(field.name.to_string());
+            let field_type = walker.parse_field_or_skip(
+                field,
+                prefix_inner,
+                field.behavior == Some(FieldBehavior::Required),
+            )?;
+            Some(TCStructField {
+                prefix: prefix.clone(), // struct fields them selfs should not contain its own field.
+                field_name: field.name.to_string(),
+                field_type,
+                doc: field.doc,
+            })
         })
         .collect();
+    walker.ancestors.pop();
 
-    let struct_name = strip_namespace(struct_info.type_path());
-
-    TCFieldType::Struct {
-        name: struct_name.to_string(),
+    Ok(TCFieldType::Struct {
+        name: schema.name.to_string(),
         fields,
-    }
+    })
 }
 
 /// This is to support proto OneOf. proto enum is primitive i32.
-/// For enums we follow serde json so that arg extraction works.
-/// It is a struct with field name as the variant name.
+/// Each variant is a field with the variant name, as in serde json.
 /// The field type is the inner type of the variant.
-fn parse_enum_to_struct(enum_info: &EnumInfo, prefix: Vec<String>) -> TCFieldType {
-    let fields = enum_info
-        .variant_names()
+fn parse_oneof(
+    schema: &'static MessageSchema,
+    prefix: Vec<String>,
+    walker: &mut Walker,
+) -> Result<TCFieldType, Error> {
+    let fields = schema
+        .fields
         .iter()
-        .map(|v_name| {
-            // prost always use single tuple variant: Enum::VariantName(InnerType)
-            let v = enum_info.variant(v_name).unwrap();
-            let v = v.as_tuple_variant().unwrap_or_else(|_| {
-                panic!("Enum variant {v_name} is not a tuple variant");
-            });
-            assert_eq!(
-                v.field_len(),
-                1,
-                "Enum variant {v_name} does not have exactly one field"
-            );
-            let field = v.field_at(0).unwrap();
-            // construct a field for the enum
-            let field_name = v_name;
+        .filter_map(|field| {
+            if field.behavior == Some(FieldBehavior::OutputOnly) {
+                return None;
+            }
             let mut prefix_inner = prefix.clone();
-            prefix_inner.push(field_name.to_string());
-            let field_type = parse_type_path(field.type_info().unwrap(), prefix_inner.clone());
-            TCStructField {
+            prefix_inner.push(field.name.to_string());
+            // only one of the oneof fields can be set.
+            let field_type = walker.parse_field_or_skip(field, prefix_inner, false)?;
+            Some(TCStructField {
                 prefix: prefix.clone(), // struct fields them selfs should not contain its own field.
-                field_name: field_name.to_string(),
+                field_name: field.name.to_string(),
                 field_type,
-            }
+                doc: field.doc,
+            })
         })
         .collect();
-    TCFieldType::Struct {
-        name: "AnonymousEnum".to_string(),
-        fields,
-    }
+    Ok(TCFieldType::OneOf { fields })
 }
 
 pub(crate) struct CallbackArgs<'a> {
@@ -246,12 +317,22 @@ pub(crate) struct CallbackArgs<'a> {
     pub field_name: &'a String,
     pub field_type: &'a TCFieldType,
     pub required: bool,
+    pub doc: Option<&'static str>,
+    /// Oneofs enclosing the field, outermost first.
+    pub oneofs: &'a [OneOfVariant],
+}
+
+/// The variant of a oneof that a field belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OneOfVariant {
+    /// Path of the oneof field, e.g. `one_of_field`.
+    pub oneof: String,
+    pub variant: String,
 }
 
 impl TCFieldTypePrimitive {
     pub fn get_clap_value_parse(&self) -> (clap::builder::ValueParser, clap::ArgAction) {
         match &self {
-            Self::U8 => (clap::value_parser!(u8).into(), clap::ArgAction::Set),
             Self::I32 => (clap::value_parser!(i32).into(), clap::ArgAction::Set),
             Self::I64 => (clap::value_parser!(i64).into(), clap::ArgAction::Set),
             Self::F32 => (clap::value_parser!(f32).into(), clap::ArgAction::Set),
@@ -260,10 +341,45 @@ impl TCFieldTypePrimitive {
             Self::U64 => (clap::value_parser!(u64).into(), clap::ArgAction::Set),
             Self::Bool => (clap::value_parser!(bool), clap::ArgAction::Set),
             Self::String => (clap::value_parser!(String), clap::ArgAction::Set),
+            Self::Enum(proto_enum) => (
+                crate::value_parser::enum_value_parser(proto_enum),
+                clap::ArgAction::Set,
+            ),
+            Self::Bytes => (
+                crate::value_parser::bytes_value_parser(),
+                clap::ArgAction::Set,
+            ),
+            Self::Json(_) | Self::Any => (
+                crate::value_parser::json_value_parser(),
+                clap::ArgAction::Set,
+            ),
+            Self::Timestamp => (
+                clap::builder::ValueParser::new(crate::wkt::parse_timestamp),
+                clap::ArgAction::Set,
+            ),
+            Self::Duration => (
+                clap::builder::ValueParser::new(crate::wkt::parse_duration),
+                clap::ArgAction::Set,
+            ),
+            Self::Map(key, value) => (
+                crate::value_parser::map_entry_value_parser(
+                    key.as_primitive(),
+                    value.as_primitive(),
+                ),
+                clap::ArgAction::Append,
+            ),
             Self::Vec(inner) => (
                 inner.as_primitive().get_clap_value_parse().0,
                 clap::ArgAction::Append,
             ),
+            Self::Struct(struct_type) => {
+                let struct_type = *struct_type;
+                (
+                    clap::builder::ValueParser::new(move |s: &str| struct_type.parse(s)),
+                    clap::ArgAction::Set,
+                )
+            }
+            Self::Wrapper(inner) => inner.get_clap_value_parse(),
         }
     }
 
@@ -275,9 +391,21 @@ impl TCFieldTypePrimitive {
             Self::F64 => "f64".into(),
             Self::Bool => "bool".into(),
             Self::String => "String".into(),
-            Self::U8 => "u8".into(),
             Self::U32 => "u32".into(),
             Self::U64 => "u64".into(),
+            Self::Enum(proto_enum) => proto_enum.name.into(),
+            Self::Bytes => "Bytes".into(),
+            Self::Json(name) => name.clone(),
+            Self::Timestamp => "Timestamp".into(),
+            Self::Duration => "Duration".into(),
+            Self::Any => "Any".into(),
+            Self::Struct(struct_type) => struct_type.name().into(),
+            Self::Wrapper(inner) => inner.display_primitive_type(),
+            Self::Map(key, value) => format!(
+                "Map<{}, {}>",
+                key.as_primitive().display_primitive_type(),
+                value.as_primitive().display_primitive_type()
+            ),
             Self::Vec(inner) => {
                 assert!(
                     inner.is_primitive(),
@@ -288,19 +416,158 @@ impl TCFieldTypePrimitive {
         }
     }
 
+    /// Input format description shown in help, if any.
+    pub fn format_hint(&self) -> Option<&'static str> {
+        match &self {
+            Self::Bytes => Some("base64, hex:<HEX> or @<FILE>"),
+            Self::Json(_) => Some("JSON"),
+            Self::Timestamp => Some("RFC 3339, now or now-5m"),
+            Self::Duration => Some("e.g. 1h30m, 250ms"),
+            Self::Any => Some("JSON with \"@type\""),
+            Self::Struct(_) => Some("JSON or @<FILE>"),
+            Self::Wrapper(inner) => inner.format_hint(),
+            Self::Vec(_) if self.value_delimiter().is_some() => {
+                Some("repeatable or comma separated")
+            }
+            Self::Vec(inner) => inner.as_primitive().format_hint(),
+            Self::Map(_, value) => match value.as_primitive() {
+                Self::Json(_) => Some("KEY=JSON, repeatable"),
+                _ => Some("KEY=VALUE, repeatable"),
+            },
+            _ => None,
+        }
+    }
+
+    /// Repeated numbers, bools and enums can also be passed comma separated.
+    /// Other types may contain commas in their values.
+    pub fn value_delimiter(&self) -> Option<char> {
+        let Self::Vec(inner) = &self else {
+            return None;
+        };
+        match inner.as_primitive() {
+            Self::U32
+            | Self::U64
+            | Self::I32
+            | Self::I64
+            | Self::F32
+            | Self::F64
+            | Self::Bool
+            | Self::Enum(_) => Some(','),
+            _ => None,
+        }
+    }
+
+    /// Values may start with '-', which clap otherwise reads as a flag.
+    pub fn is_signed_number(&self) -> bool {
+        match &self {
+            Self::I32 | Self::I64 | Self::F32 | Self::F64 => true,
+            Self::Vec(inner) => inner.as_primitive().is_signed_number(),
+            Self::Wrapper(inner) => inner.is_signed_number(),
+            _ => false,
+        }
+    }
+
     pub fn is_vec(&self) -> bool {
         matches!(&self, Self::Vec { .. })
     }
 
+    /// Vec or Map.
+    pub fn is_collection(&self) -> bool {
+        matches!(&self, Self::Vec(_) | Self::Map(..))
+    }
+
+    /// Whether the values are messages given as proto3 JSON, possibly in a collection.
+    pub fn takes_message_json(&self) -> bool {
+        match self {
+            Self::Json(_) | Self::Any => true,
+            Self::Vec(inner) | Self::Map(_, inner) => {
+                inner.is_primitive() && inner.as_primitive().takes_message_json()
+            }
+            _ => false,
+        }
+    }
+
     /// Check if the type is a primitive vector. nested vector is not primitive
     pub fn is_primitive_vec(&self) -> bool {
         matches!(&self, Self::Vec(inner) if inner.is_primitive() && !inner.as_primitive().is_vec())
     }
 }
 
+impl TCStructField {
+    fn visit(&self, oneofs: &mut Vec<OneOfVariant>, f: &mut dyn FnMut(&CallbackArgs)) {
+        if self.field_type.is_primitive() {
+            f(&CallbackArgs {
+                prefix: &self.prefix,
+                field_name: &self.field_name,
+                field_type: &self.field_type,
+                required: self.field_type.is_primitive_required(),
+                doc: self.doc,
+                oneofs,
+            });
+        } else if let TCFieldType::Option(inner) = &self.field_type
+            && inner.is_primitive()
+        {
+            // option primitive
+            f(&CallbackArgs {
+                prefix: &self.prefix,
+                field_name: &self.field_name,
+                field_type: inner,
+                required: inner.is_primitive_required(),
+                doc: self.doc,
+                oneofs,
+            });
+        } else {
+            self.field_type.visit_nested_in(oneofs, f);
+        }
+    }
+}
+
+/// Field tree of a request type.
+#[derive(Debug)]
+pub(crate) struct ParsedRequest {
+    pub tree: TCFieldType,
+    /// Why fields were left out of the cli, one message per field.
+    pub skipped: Vec<String>,
+}
+
+impl ParsedRequest {
+    fn parse(schema: &'static MessageSchema) -> Result<Self, Error> {
+        let mut walker = Walker::new(schema);
+        let tree = parse_struct(schema, vec![], &mut walker)?;
+        Ok(Self {
+            tree,
+            skipped: walker.skipped,
+        })
+    }
+}
+
 impl TCFieldType {
-    pub fn parse(type_info: &TypeInfo) -> Self {
-        parse_struct(type_info, vec![])
+    /// Fails only if the request type itself is not supported.
+    /// Unsupported fields are skipped, see `ParsedRequest::skipped`.
+    pub fn parse(schema: &'static MessageSchema) -> Result<Self, Error> {
+        Ok(ParsedRequest::parse(schema)?.tree)
+    }
+
+    /// Same as `parse`, but each message is only walked once per process.
+    pub fn parse_cached(schema: &'static MessageSchema) -> Result<Arc<ParsedRequest>, Error> {
+        // Schemas are statics, so the address identifies the message.
+        static CACHE: OnceLock<RwLock<HashMap<usize, Arc<ParsedRequest>>>> = OnceLock::new();
+        let cache = CACHE.get_or_init(Default::default);
+        let key = std::ptr::from_ref(schema) as usize;
+        if let Some(parsed) = cache.read().unwrap().get(&key) {
+            return Ok(parsed.clone());
+        }
+        let parsed = Arc::new(ParsedRequest::parse(schema)?);
+        Ok(cache.write().unwrap().entry(key).or_insert(parsed).clone())
+    }
+
+    /// Mark the flag of this field as required or not.
+    fn set_required(&mut self, value: bool) {
+        match self {
+            TCFieldType::Primitive { required, .. } => *required = value,
+            TCFieldType::Option(inner) => inner.set_required(value),
+            _ => {}
+        }
     }
 
     pub fn is_primitive_option(&self) -> bool {
@@ -310,33 +577,24 @@ impl TCFieldType {
     /// Visit all nested fields
     /// Callback is only applied on primitive types.
     pub fn visit_nested(&self, f: &mut dyn FnMut(&CallbackArgs)) {
+        self.visit_nested_in(&mut Vec::new(), f);
+    }
+
+    fn visit_nested_in(&self, oneofs: &mut Vec<OneOfVariant>, f: &mut dyn FnMut(&CallbackArgs)) {
         match &self {
             TCFieldType::Struct { fields, .. } => {
                 for field in fields {
-                    if field.field_type.is_primitive() {
-                        let is_required = field.field_type.is_primitive_required();
-                        f(&CallbackArgs {
-                            prefix: &field.prefix,
-                            field_name: &field.field_name,
-                            field_type: &field.field_type,
-                            required: is_required,
-                        });
-                    } else if field.field_type.is_primitive_option() {
-                        // option primitive
-                        let inner = if let TCFieldType::Option(inner) = &field.field_type {
-                            inner
-                        } else {
-                            unreachable!();
-                        };
-                        f(&CallbackArgs {
-                            prefix: &field.prefix,
-                            field_name: &field.field_name,
-                            field_type: inner,
-                            required: false,
-                        });
-                    } else {
-                        field.field_type.visit_nested(f);
-                    }
+                    field.visit(oneofs, f);
+                }
+            }
+            TCFieldType::OneOf { fields } => {
+                for field in fields {
+                    oneofs.push(OneOfVariant {
+                        oneof: field.prefix.join("."),
+                        variant: field.field_name.clone(),
+                    });
+                    field.visit(oneofs, f);
+                    oneofs.pop();
                 }
             }
             TCFieldType::Option(inner) => {
@@ -344,10 +602,7 @@ impl TCFieldType {
                     !inner.is_primitive(),
                     "Option primitive should be handled in parent struct: {self:?}"
                 );
-                inner.visit_nested(f);
-            }
-            TCFieldType::Unknown(_) => {
-                // skip well known unknown types.
+                inner.visit_nested_in(oneofs, f);
             }
             TCFieldType::Primitive { .. } => {
                 // Note that we do not process none primitive Vec elements.
@@ -380,22 +635,10 @@ impl TCFieldType {
 
 #[cfg(test)]
 mod tests {
-    use bevy_reflect::Typed;
-
     use super::*;
+    use crate::schema::Message;
 
-    #[test]
-    fn test_strip_namespace() {
-        assert_eq!(strip_namespace("std::string::String"), "String");
-        assert_eq!(strip_namespace("std::vec::Vec"), "Vec");
-        assert_eq!(strip_namespace("Vec"), "Vec");
-        assert_eq!(
-            strip_namespace("alloc::vec::Vec<alloc::string::String>"),
-            "Vec<alloc::string::String>"
-        );
-    }
-
-    #[derive(bevy_reflect::Reflect, Default)]
+    #[derive(Default, serde::Deserialize, crate::TonicClap)]
     struct Struct1 {
         field0: i32,
         field1: String,
@@ -403,21 +646,21 @@ mod tests {
         field3: Option<i64>,
     }
 
-    #[derive(bevy_reflect::Reflect, Default)]
+    #[derive(Default, serde::Deserialize, crate::TonicClap)]
     struct Struct2 {
         field0: f64,
         field1: Vec<String>,
         field2: Struct3,
     }
 
-    #[derive(bevy_reflect::Reflect, Default)]
+    #[derive(Default, serde::Deserialize, crate::TonicClap)]
     struct Struct3 {
         field0: i32,
     }
 
     #[test]
     fn test_parse_type() {
-        let parsed = TCFieldType::parse(Struct1::type_info());
+        let parsed = TCFieldType::parse(Struct1::schema()).unwrap();
         if let TCFieldType::Struct { name, fields } = parsed {
             assert_eq!(name, "Struct1");
             assert_eq!(fields.len(), 4);
@@ -426,9 +669,10 @@ mod tests {
                 TCStructField {
                     prefix: vec![],
                     field_name: "field0".into(),
+                    doc: None,
                     field_type: TCFieldType::Primitive {
                         field_type: TCFieldTypePrimitive::I32,
-                        required: true
+                        required: false
                     }
                 }
             );
@@ -437,9 +681,10 @@ mod tests {
                 TCStructField {
                     prefix: vec![],
                     field_name: "field1".into(),
+                    doc: None,
                     field_type: TCFieldType::Primitive {
                         field_type: TCFieldTypePrimitive::String,
-                        required: true
+                        required: false
                     }
                 }
             );
@@ -448,20 +693,23 @@ mod tests {
                 TCStructField {
                     prefix: vec![],
                     field_name: "field2".into(),
+                    doc: None,
                     field_type: TCFieldType::Struct {
                         name: "Struct2".into(),
                         fields: vec![
                             TCStructField {
                                 prefix: vec!["field2".to_string()],
                                 field_name: "field0".into(),
+                                doc: None,
                                 field_type: TCFieldType::Primitive {
                                     field_type: TCFieldTypePrimitive::F64,
-                                    required: true
+                                    required: false
                                 }
                             },
                             TCStructField {
                                 prefix: vec!["field2".to_string()],
                                 field_name: "field1".into(),
+                                doc: None,
                                 field_type: TCFieldType::Primitive {
                                     field_type: TCFieldTypePrimitive::Vec(Box::new(
                                         TCFieldType::Primitive {
@@ -475,14 +723,16 @@ mod tests {
                             TCStructField {
                                 prefix: vec!["field2".to_string()],
                                 field_name: "field2".into(),
+                                doc: None,
                                 field_type: TCFieldType::Struct {
                                     name: "Struct3".into(),
                                     fields: vec![TCStructField {
                                         prefix: vec!["field2".to_string(), "field2".to_string()],
                                         field_name: "field0".into(),
+                                        doc: None,
                                         field_type: TCFieldType::Primitive {
                                             field_type: TCFieldTypePrimitive::I32,
-                                            required: true
+                                            required: false
                                         }
                                     },]
                                 }
@@ -496,9 +746,10 @@ mod tests {
                 TCStructField {
                     prefix: vec![],
                     field_name: "field3".into(),
+                    doc: None,
                     field_type: TCFieldType::Option(Box::new(TCFieldType::Primitive {
                         field_type: TCFieldTypePrimitive::I64,
-                        required: true
+                        required: false
                     }))
                 }
             );
@@ -506,4 +757,153 @@ mod tests {
             panic!("not a struct");
         };
     }
+
+    #[derive(Default, serde::Deserialize, crate::TonicClap)]
+    struct Node {
+        name: String,
+        children: Vec<Node>,
+        wrapped: Wrapped,
+    }
+
+    #[derive(Default, serde::Deserialize, crate::TonicClap)]
+    struct Wrapped {
+        nodes: Vec<Node>,
+    }
+
+    #[derive(Default, serde::Deserialize, crate::TonicClap)]
+    #[tonic_clap(max_flag_depth = 2)]
+    struct Shallow {
+        field0: Struct2,
+        field1: Node,
+    }
+
+    fn field<'a>(parsed: &'a TCFieldType, name: &str) -> &'a TCFieldType {
+        let TCFieldType::Struct { fields, .. } = parsed else {
+            panic!("not a struct: {parsed:?}");
+        };
+        &fields
+            .iter()
+            .find(|f| f.field_name == name)
+            .unwrap()
+            .field_type
+    }
+
+    #[test]
+    fn test_parse_recursive_type() {
+        let json = |name: &str| TCFieldType::Primitive {
+            field_type: TCFieldTypePrimitive::Json(name.into()),
+            required: true,
+        };
+        let parsed = TCFieldType::parse(Node::schema()).unwrap();
+        assert_eq!(
+            field(&parsed, "children").as_primitive(),
+            &TCFieldTypePrimitive::Vec(Box::new(json("Node")))
+        );
+        // Node is not expanded again under itself.
+        let wrapped = field(&parsed, "wrapped");
+        assert_eq!(
+            field(wrapped, "nodes").as_primitive(),
+            &TCFieldTypePrimitive::Vec(Box::new(json("Node")))
+        );
+
+        let parsed = TCFieldType::parse(Shallow::schema()).unwrap();
+        let field0 = field(&parsed, "field0");
+        assert!(field(field0, "field0").is_primitive());
+        assert_eq!(
+            field(field0, "field2").as_primitive(),
+            json("Struct3").as_primitive()
+        );
+        assert_eq!(
+            field(field(&parsed, "field1"), "wrapped").as_primitive(),
+            json("Wrapped").as_primitive()
+        );
+    }
+
+    #[derive(Default, serde::Deserialize, crate::TonicClap)]
+    struct Behaviors {
+        #[tonic_clap(required)]
+        name: String,
+        #[tonic_clap(output_only)]
+        id: String,
+        #[tonic_clap(required)]
+        inner: Option<Inner>,
+        outer: Inner,
+    }
+
+    #[derive(Default, serde::Deserialize, crate::TonicClap)]
+    struct Inner {
+        #[tonic_clap(required)]
+        key: Option<String>,
+        value: String,
+    }
+
+    #[test]
+    fn test_parse_field_behavior() {
+        let parsed = TCFieldType::parse(Behaviors::schema()).unwrap();
+        let TCFieldType::Struct { fields, .. } = &parsed else {
+            panic!("not a struct: {parsed:?}");
+        };
+        let names = fields
+            .iter()
+            .map(|f| f.field_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["name", "inner", "outer"]);
+
+        let mut required = Vec::new();
+        parsed.visit_nested(&mut |ctx| {
+            if ctx.required {
+                let mut path = ctx.prefix.clone();
+                path.push(ctx.field_name.clone());
+                required.push(path.join("."));
+            }
+        });
+        // outer is not required, so neither is outer.key.
+        assert_eq!(required, ["name", "inner.key"]);
+    }
+
+    #[derive(Default, serde::Deserialize, crate::TonicClap)]
+    struct Unsupported {
+        name: String,
+        shapes: Vec<Shape>,
+        inner: Inner,
+    }
+
+    #[derive(serde::Deserialize, crate::TonicClap)]
+    enum Shape {
+        Circle(f64),
+        Pair(Inner),
+    }
+
+    #[test]
+    fn test_parse_unsupported() {
+        let parsed = TCFieldType::parse(Unsupported::schema()).unwrap();
+        let TCFieldType::Struct { fields, .. } = &parsed else {
+            panic!("not a struct: {parsed:?}");
+        };
+        let names = fields
+            .iter()
+            .map(|f| f.field_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["name", "inner"]);
+        let skipped = ParsedRequest::parse(Unsupported::schema()).unwrap().skipped;
+        assert_eq!(skipped.len(), 1);
+        assert!(skipped[0].contains("at shapes"), "{skipped:?}");
+
+        let mut walker = Walker::new(Unsupported::schema());
+        let err = walker
+            .parse_field(
+                &Unsupported::schema().fields[1],
+                vec!["outer".into(), "shapes".into()],
+                false,
+            )
+            .unwrap_err();
+        let Error::UnsupportedType {
+            type_path, prefix, ..
+        } = &err
+        else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(type_path, "Shape", "{err}");
+        assert_eq!(prefix, "outer.shapes");
+    }
 }
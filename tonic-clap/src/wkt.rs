@@ -0,0 +1,438 @@
+// Support for google.protobuf well known types.
+// prost compiles them as plain structs, but on the command line they have a
+// more natural text form.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::Value;
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Timestamp from RFC 3339 (`2024-01-02T03:04:05.5+01:00`), `now`, or `now` plus/minus a duration (`now-5m`).
+/// Returns the serde json of google.protobuf.Timestamp.
+pub(crate) fn parse_timestamp(s: &str) -> Result<Value, String> {
+    let (seconds, nanos) = if let Some(rest) = s.strip_prefix("now") {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| e.to_string())?
+            .as_nanos() as i128;
+        let offset = if rest.is_empty() {
+            0
+        } else if let Some(d) = rest.strip_prefix('+') {
+            duration_nanos(d)?
+        } else if let Some(d) = rest.strip_prefix('-') {
+            -duration_nanos(d)?
+        } else {
+            return Err(format!(
+                "invalid timestamp '{s}', expected now, now+<DURATION> or now-<DURATION>"
+            ));
+        };
+        // Timestamp nanos are never negative.
+        let total = now + offset;
+        (
+            total.div_euclid(NANOS_PER_SECOND) as i64,
+            total.rem_euclid(NANOS_PER_SECOND) as i32,
+        )
+    } else {
+        parse_rfc3339(s)?
+    };
+    Ok(serde_json::json!({ "seconds": seconds, "nanos": nanos }))
+}
+
+/// Duration such as `1h30m`, `250ms` or `-5s`.
+/// Returns the serde json of google.protobuf.Duration.
+pub(crate) fn parse_duration(s: &str) -> Result<Value, String> {
+    let total = match s.strip_prefix('-') {
+        Some(d) => -duration_nanos(d)?,
+        None => duration_nanos(s)?,
+    };
+    // Duration seconds and nanos have the same sign.
+    let seconds = i64::try_from(total / NANOS_PER_SECOND)
+        .map_err(|_| format!("duration '{s}' is out of range"))?;
+    let nanos = (total % NANOS_PER_SECOND) as i32;
+    Ok(serde_json::json!({ "seconds": seconds, "nanos": nanos }))
+}
+
+/// google.protobuf.Struct and friends, which hold arbitrary JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StructType {
+    Struct,
+    Value,
+    ListValue,
+}
+
+impl StructType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Struct" => Some(Self::Struct),
+            "Value" => Some(Self::Value),
+            "ListValue" => Some(Self::ListValue),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Struct => "Struct",
+            Self::Value => "Value",
+            Self::ListValue => "ListValue",
+        }
+    }
+
+    /// Parse a JSON literal, or `@path` to a JSON file, into the serde JSON of the type.
+    pub fn parse(&self, s: &str) -> Result<Value, String> {
+        let json = match s.strip_prefix('@') {
+            Some(path) => {
+                let data = std::fs::read_to_string(path)
+                    .map_err(|e| format!("cannot read file {path}: {e}"))?;
+                serde_json::from_str::<Value>(&data)
+                    .map_err(|e| format!("invalid json in {path}: {e}"))?
+            }
+            None => serde_json::from_str::<Value>(s).map_err(|e| format!("invalid json: {e}"))?,
+        };
+        self.convert_json(json)
+    }
+
+    /// Convert the plain JSON to the serde JSON of the type.
+    pub fn convert_json(&self, json: Value) -> Result<Value, String> {
+        match (self, json) {
+            (Self::Struct, Value::Object(obj)) => Ok(struct_from_json(obj)),
+            (Self::ListValue, Value::Array(items)) => Ok(list_from_json(items)),
+            (Self::Value, json) => Ok(value_from_json(json)),
+            (Self::Struct, _) => Err("expected a JSON object".to_string()),
+            (Self::ListValue, _) => Err("expected a JSON array".to_string()),
+        }
+    }
+}
+
+// prost Value holds the JSON kind in a oneof: `{"kind": {"NumberValue": 1.0}}`.
+fn value_from_json(json: Value) -> Value {
+    let kind = match json {
+        Value::Null => serde_json::json!({ "NullValue": 0 }),
+        Value::Bool(b) => serde_json::json!({ "BoolValue": b }),
+        Value::Number(n) => serde_json::json!({ "NumberValue": n.as_f64() }),
+        Value::String(s) => serde_json::json!({ "StringValue": s }),
+        Value::Array(items) => serde_json::json!({ "ListValue": list_from_json(items) }),
+        Value::Object(obj) => serde_json::json!({ "StructValue": struct_from_json(obj) }),
+    };
+    serde_json::json!({ "kind": kind })
+}
+
+fn struct_from_json(obj: serde_json::Map<String, Value>) -> Value {
+    let fields = obj
+        .into_iter()
+        .map(|(k, v)| (k, value_from_json(v)))
+        .collect::<serde_json::Map<_, _>>();
+    serde_json::json!({ "fields": fields })
+}
+
+fn list_from_json(items: Vec<Value>) -> Value {
+    let values = items.into_iter().map(value_from_json).collect::<Vec<_>>();
+    serde_json::json!({ "values": values })
+}
+
+/// The plain JSON held by the serde JSON of a Struct, Value or ListValue.
+pub(crate) fn struct_to_json(value: &Value) -> Option<Value> {
+    let obj = value.as_object().filter(|o| o.len() == 1)?;
+    match obj.iter().next()? {
+        (k, Value::Object(fields)) if k == "fields" => fields
+            .iter()
+            .map(|(k, v)| Some((k.clone(), value_to_json(v)?)))
+            .collect::<Option<serde_json::Map<_, _>>>()
+            .map(Value::Object),
+        (k, Value::Array(values)) if k == "values" => values
+            .iter()
+            .map(value_to_json)
+            .collect::<Option<Vec<_>>>()
+            .map(Value::Array),
+        (k, _) if k == "kind" => value_to_json(value),
+        _ => None,
+    }
+}
+
+fn value_to_json(value: &Value) -> Option<Value> {
+    let kind = value.as_object().filter(|o| o.len() == 1)?.get("kind")?;
+    if kind.is_null() {
+        return Some(Value::Null);
+    }
+    let (name, inner) = kind.as_object().filter(|o| o.len() == 1)?.iter().next()?;
+    match name.as_str() {
+        "NullValue" => Some(Value::Null),
+        "BoolValue" | "NumberValue" | "StringValue" => Some(inner.clone()),
+        "StructValue" | "ListValue" => struct_to_json(inner),
+        _ => None,
+    }
+}
+
+/// Duration in the proto3 JSON form, e.g. `1.5s`, or as taken by [`parse_duration`].
+pub(crate) fn parse_proto_duration(s: &str) -> Result<Value, String> {
+    let Some((negative, seconds, frac)) = s.strip_suffix('s').and_then(|d| {
+        let (negative, d) = match d.strip_prefix('-') {
+            Some(d) => (true, d),
+            None => (false, d),
+        };
+        let (seconds, frac) = d.split_once('.').unwrap_or((d, ""));
+        let digits = |s: &str| s.bytes().all(|c| c.is_ascii_digit());
+        (!seconds.is_empty() && digits(seconds) && digits(frac) && frac.len() <= 9)
+            .then_some((negative, seconds, frac))
+    }) else {
+        return parse_duration(s);
+    };
+    let seconds = seconds
+        .parse::<i64>()
+        .map_err(|_| format!("duration '{s}' is out of range"))?;
+    let nanos = format!("{frac:0<9}").parse::<i32>().unwrap_or(0);
+    // Duration seconds and nanos have the same sign.
+    let (seconds, nanos) = if negative {
+        (-seconds, -nanos)
+    } else {
+        (seconds, nanos)
+    };
+    Ok(serde_json::json!({ "seconds": seconds, "nanos": nanos }))
+}
+
+/// RFC 3339 in UTC with 0, 3, 6 or 9 fractional digits, as in proto3 JSON.
+pub(crate) fn format_timestamp(seconds: i64, nanos: i32) -> String {
+    let (days, secs) = (seconds.div_euclid(86400), seconds.rem_euclid(86400));
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}{}Z",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60,
+        fraction(nanos.unsigned_abs())
+    )
+}
+
+/// Seconds with 0, 3, 6 or 9 fractional digits and an `s` suffix, e.g. `-1.500s`.
+pub(crate) fn format_duration(seconds: i64, nanos: i32) -> String {
+    let sign = if seconds < 0 || nanos < 0 { "-" } else { "" };
+    format!(
+        "{sign}{}{}s",
+        seconds.unsigned_abs(),
+        fraction(nanos.unsigned_abs())
+    )
+}
+
+fn fraction(nanos: u32) -> String {
+    match nanos {
+        0 => String::new(),
+        n if n % 1_000_000 == 0 => format!(".{:03}", n / 1_000_000),
+        n if n % 1_000 == 0 => format!(".{:06}", n / 1_000),
+        n => format!(".{n:09}"),
+    }
+}
+
+fn duration_nanos(s: &str) -> Result<i128, String> {
+    humantime::parse_duration(s)
+        .map(|d| d.as_nanos() as i128)
+        .map_err(|e| format!("invalid duration '{s}': {e}"))
+}
+
+fn parse_rfc3339(s: &str) -> Result<(i64, i32), String> {
+    let err = || {
+        format!(
+            "invalid timestamp '{s}', expected RFC 3339 such as 2024-01-02T03:04:05Z, now or now-5m"
+        )
+    };
+    let num = |start: usize, end: usize| -> Result<i64, String> {
+        s.get(start..end)
+            .filter(|d| d.bytes().all(|c| c.is_ascii_digit()))
+            .and_then(|d| d.parse::<i64>().ok())
+            .ok_or_else(err)
+    };
+    let b = s.as_bytes();
+    if b.len() < 20
+        || b[4] != b'-'
+        || b[7] != b'-'
+        || !matches!(b[10], b'T' | b't' | b' ')
+        || b[13] != b':'
+        || b[16] != b':'
+    {
+        return Err(err());
+    }
+    let (year, month, day) = (num(0, 4)?, num(5, 7)?, num(8, 10)?);
+    let (hour, minute, second) = (num(11, 13)?, num(14, 16)?, num(17, 19)?);
+    if !(1..=12).contains(&month)
+        || day < 1
+        || day > days_in_month(year, month)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return Err(err());
+    }
+
+    let mut rest = &s[19..];
+    let mut nanos = 0;
+    if let Some(frac) = rest.strip_prefix('.') {
+        let digits = frac.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 || digits > 9 {
+            return Err(err());
+        }
+        nanos = frac[..digits].parse::<i32>().map_err(|_| err())? * 10_i32.pow(9 - digits as u32);
+        rest = &frac[digits..];
+    }
+
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ => {
+            let rb = rest.as_bytes();
+            if rb.len() != 6 || rb[3] != b':' {
+                return Err(err());
+            }
+            let sign = match rb[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return Err(err()),
+            };
+            let base = s.len() - 6;
+            sign * (num(base + 1, base + 3)? * 3600 + num(base + 4, base + 6)? * 60)
+        }
+    };
+
+    let seconds =
+        days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second - offset;
+    Ok((seconds, nanos))
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01 in the proleptic gregorian calendar.
+// See http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+// Reverse of days_from_civil.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(seconds: i64, nanos: i32) -> Value {
+        serde_json::json!({ "seconds": seconds, "nanos": nanos })
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00Z").unwrap(), ts(0, 0));
+        assert_eq!(
+            parse_timestamp("2024-02-29T12:00:00.5+01:00").unwrap(),
+            ts(1709204400, 500_000_000)
+        );
+        assert_eq!(
+            parse_timestamp("1969-12-31t23:59:59.000000001z").unwrap(),
+            ts(-1, 1)
+        );
+        for bad in [
+            "2023-02-29T00:00:00Z",
+            "2024-01-01T00:00:00",
+            "2024-01-01T24:00:00Z",
+            "2024-01-01T00:00:00.Z",
+            "2024-01-01T00:00:00+0100",
+            "yesterday",
+            "now*5m",
+        ] {
+            assert!(parse_timestamp(bad).is_err(), "{bad}");
+        }
+
+        let now = parse_timestamp("now").unwrap()["seconds"].as_i64().unwrap();
+        let before = parse_timestamp("now-5m").unwrap()["seconds"]
+            .as_i64()
+            .unwrap();
+        let after = parse_timestamp("now+1h").unwrap()["seconds"]
+            .as_i64()
+            .unwrap();
+        assert!((now - before - 300).abs() <= 1);
+        assert!((after - now - 3600).abs() <= 1);
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(
+            parse_duration("1h30m").unwrap(),
+            serde_json::json!({ "seconds": 5400, "nanos": 0 })
+        );
+        assert_eq!(
+            parse_duration("250ms").unwrap(),
+            serde_json::json!({ "seconds": 0, "nanos": 250_000_000 })
+        );
+        assert_eq!(
+            parse_duration("-1s 500ms").unwrap(),
+            serde_json::json!({ "seconds": -1, "nanos": -500_000_000 })
+        );
+        assert!(parse_duration("1 parsec").is_err());
+    }
+
+    #[test]
+    fn test_struct_json() {
+        let plain = serde_json::json!({"a": [1.5, "x", true, null], "b": {"c": {}}});
+        let parsed = StructType::Struct.parse(&plain.to_string()).unwrap();
+        assert_eq!(
+            parsed["fields"]["a"]["kind"]["ListValue"]["values"][0],
+            serde_json::json!({"kind": {"NumberValue": 1.5}})
+        );
+        assert_eq!(struct_to_json(&parsed), Some(plain));
+
+        let value = StructType::Value.parse("3").unwrap();
+        assert_eq!(struct_to_json(&value), Some(serde_json::json!(3.0)));
+        assert!(StructType::Struct.parse("[1]").is_err());
+        assert!(StructType::ListValue.parse("{}").is_err());
+        assert!(StructType::Value.parse("{").is_err());
+
+        assert_eq!(
+            struct_to_json(&serde_json::json!({"kind": {"Other": 1}})),
+            None
+        );
+    }
+
+    #[test]
+    fn test_proto_json_time() {
+        for (text, seconds, nanos) in [
+            ("1970-01-01T00:00:00Z", 0, 0),
+            ("2024-02-29T11:00:00.500Z", 1709204400, 500_000_000),
+            ("1969-12-31T23:59:59.000000001Z", -1, 1),
+            ("2000-03-01T00:00:00.000010Z", 951868800, 10_000),
+        ] {
+            assert_eq!(format_timestamp(seconds, nanos), text);
+            assert_eq!(parse_timestamp(text).unwrap(), ts(seconds, nanos));
+        }
+        for (text, seconds, nanos) in [
+            ("0s", 0, 0),
+            ("1.500s", 1, 500_000_000),
+            ("-0.000000001s", 0, -1),
+        ] {
+            assert_eq!(format_duration(seconds, nanos), text);
+            assert_eq!(parse_proto_duration(text).unwrap(), ts(seconds, nanos));
+        }
+        assert_eq!(parse_proto_duration("1.5s").unwrap(), ts(1, 500_000_000));
+        assert_eq!(parse_proto_duration("1m").unwrap(), ts(60, 0));
+        assert!(parse_proto_duration("1.0000000001s").is_err());
+    }
+}